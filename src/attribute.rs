@@ -0,0 +1,103 @@
+use crate::{Decode, Encode, GenericVec, OperationError, SpreadLayout, String};
+use ink_storage::traits::PackedLayout;
+
+pub const MIN_SDG: u8 = 1;
+pub const MAX_SDG: u8 = 17;
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, SpreadLayout, PackedLayout, Encode, Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CreditKind {
+    Removal,
+    Avoidance,
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, SpreadLayout, PackedLayout, Encode, Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CreditOrigin {
+    NatureBased,
+    Technological,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Attributes {
+    pub methodology: String,
+    pub kind: CreditKind,
+    pub origin: CreditOrigin,
+    pub country: [u8; 2],
+    pub sdgs: GenericVec<u8>,
+    pub ratings: GenericVec<String>,
+}
+
+#[derive(Clone, Debug, Default, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Filter {
+    pub methodology: Option<String>,
+    pub kind: Option<CreditKind>,
+    pub origin: Option<CreditOrigin>,
+    pub country: Option<[u8; 2]>,
+    pub sdg: Option<u8>,
+    pub rating: Option<String>,
+}
+
+impl Attributes {
+    pub fn validate(&self) -> Result<(), OperationError> {
+        if self.methodology.is_empty()
+            || !self
+                .country
+                .iter()
+                .all(|letter| letter.is_ascii_uppercase())
+            || self.sdgs.iter().any(|sdg| *sdg < MIN_SDG || *sdg > MAX_SDG)
+        {
+            return Err(OperationError::InvalidCreditAttributes);
+        }
+
+        Ok(())
+    }
+}
+
+impl Filter {
+    pub fn matches(&self, attributes: &Attributes) -> bool {
+        if let Some(methodology) = &self.methodology {
+            if *methodology != attributes.methodology {
+                return false;
+            }
+        }
+
+        if let Some(kind) = self.kind {
+            if kind != attributes.kind {
+                return false;
+            }
+        }
+
+        if let Some(origin) = self.origin {
+            if origin != attributes.origin {
+                return false;
+            }
+        }
+
+        if let Some(country) = self.country {
+            if country != attributes.country {
+                return false;
+            }
+        }
+
+        if let Some(sdg) = self.sdg {
+            if !attributes.sdgs.contains(&sdg) {
+                return false;
+            }
+        }
+
+        if let Some(rating) = &self.rating {
+            if !attributes.ratings.contains(rating) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
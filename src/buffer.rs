@@ -0,0 +1,131 @@
+use crate::attribute::{CreditKind, CreditOrigin};
+use crate::token::Detail as TokenDetail;
+use crate::{
+    CarbonUnit, Decode, Encode, OperationError, ProjectId, SpreadLayout, StorageHashMap, TokenId,
+};
+
+pub const BUFFER_BASIS_POINTS: CarbonUnit = 10_000;
+
+#[derive(Clone, Debug, Default, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Stats {
+    pub withheld: CarbonUnit,
+    pub cancelled: CarbonUnit,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Reserve {
+    project_rates: StorageHashMap<ProjectId, u16>,
+    category_rates: StorageHashMap<(CreditKind, CreditOrigin), u16>,
+    withheld: StorageHashMap<TokenId, CarbonUnit>,
+    covered: StorageHashMap<TokenId, CarbonUnit>,
+    total_withheld: CarbonUnit,
+    total_cancelled: CarbonUnit,
+}
+
+impl Reserve {
+    pub fn set_project_rate(
+        &mut self,
+        project_id: ProjectId,
+        rate_bps: Option<u16>,
+    ) -> Result<(), OperationError> {
+        match rate_bps {
+            None => {
+                self.project_rates.take(&project_id);
+            }
+            Some(rate_bps) => {
+                if rate_bps as CarbonUnit >= BUFFER_BASIS_POINTS {
+                    return Err(OperationError::InvalidBufferRate);
+                }
+
+                self.project_rates.insert(project_id, rate_bps);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn set_category_rate(
+        &mut self,
+        kind: CreditKind,
+        origin: CreditOrigin,
+        rate_bps: Option<u16>,
+    ) -> Result<(), OperationError> {
+        match rate_bps {
+            None => {
+                self.category_rates.take(&(kind, origin));
+            }
+            Some(rate_bps) => {
+                if rate_bps as CarbonUnit >= BUFFER_BASIS_POINTS {
+                    return Err(OperationError::InvalidBufferRate);
+                }
+
+                self.category_rates.insert((kind, origin), rate_bps);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_rate(&self, token_detail: &TokenDetail) -> u16 {
+        if let Some(rate_bps) = self.project_rates.get(&token_detail.project_id) {
+            return *rate_bps;
+        }
+
+        let category = (token_detail.attributes.kind, token_detail.attributes.origin);
+
+        match self.category_rates.get(&category) {
+            None => 0,
+            Some(rate_bps) => *rate_bps,
+        }
+    }
+
+    pub fn get_buffer_amount(&self, token_detail: &TokenDetail, amount: CarbonUnit) -> CarbonUnit {
+        amount * self.get_rate(token_detail) as CarbonUnit / BUFFER_BASIS_POINTS
+    }
+
+    pub fn record_withheld(&mut self, token_id: TokenId, amount: CarbonUnit) {
+        let withheld = self.get_withheld_by_id(token_id);
+        self.withheld.insert(token_id, withheld + amount);
+        self.total_withheld += amount;
+    }
+
+    pub fn check_can_cover(
+        &self,
+        covered_token_id: TokenId,
+        amount: CarbonUnit,
+        cancelled: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        match self.get_covered_by_id(covered_token_id).checked_add(amount) {
+            Some(covered) if covered <= cancelled => Ok(()),
+            _ => Err(OperationError::BufferCoverageExceeded),
+        }
+    }
+
+    pub fn record_cancelled(&mut self, covered_token_id: TokenId, amount: CarbonUnit) {
+        let covered = self.get_covered_by_id(covered_token_id);
+        self.covered.insert(covered_token_id, covered + amount);
+        self.total_cancelled += amount;
+    }
+
+    pub fn get_covered_by_id(&self, covered_token_id: TokenId) -> CarbonUnit {
+        match self.covered.get(&covered_token_id) {
+            None => 0,
+            Some(amount) => *amount,
+        }
+    }
+
+    pub fn get_withheld_by_id(&self, token_id: TokenId) -> CarbonUnit {
+        match self.withheld.get(&token_id) {
+            None => 0,
+            Some(amount) => *amount,
+        }
+    }
+
+    pub fn get_stats(&self) -> Stats {
+        Stats {
+            withheld: self.total_withheld,
+            cancelled: self.total_cancelled,
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Hash, Timestamp};
+use crate::pagination::{paginate_indexed, Page};
+use crate::{
+    ChallengeId, Decode, Encode, GenericVec, OperationError, RegistryId, SpreadLayout,
+    StorageHashMap, TokenId,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+pub const MAX_OPEN_CHALLENGES_PER_EDITION: u32 = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Resolution {
+    Dismissed,
+    Upheld,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, SpreadLayout, PackedLayout, Encode, Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Subject {
+    Edition(TokenId),
+    Supplement(RegistryId),
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Challenge {
+    pub id: ChallengeId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub token_id: TokenId,
+    pub subject: Subject,
+    pub challenger: AccountId,
+    pub evidence_hash: Hash,
+    pub resolution: Option<Resolution>,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Docket {
+    next_challenge_id: ChallengeId,
+    challenges: StorageHashMap<ChallengeId, Challenge>,
+    edition_challenges: StorageHashMap<TokenId, GenericVec<ChallengeId>>,
+    open_challenges: StorageHashMap<Subject, u32>,
+    open_challengers: StorageHashMap<(AccountId, Subject), ChallengeId>,
+    upheld_challenges: StorageHashMap<Subject, ChallengeId>,
+    challenge_period: Option<BlockNumber>,
+}
+
+impl Docket {
+    pub fn take_next_challenge_id(&mut self) -> ChallengeId {
+        let next_challenge_id = self.next_challenge_id;
+        self.next_challenge_id += 1;
+
+        next_challenge_id
+    }
+
+    pub fn get_challenge_period(&self) -> Option<BlockNumber> {
+        self.challenge_period
+    }
+
+    pub fn set_challenge_period(&mut self, challenge_period: Option<BlockNumber>) {
+        self.challenge_period = challenge_period;
+    }
+
+    pub fn file(
+        &mut self,
+        token_id: TokenId,
+        subject: Subject,
+        challenger: AccountId,
+        evidence_hash: Hash,
+    ) -> Result<Challenge, OperationError> {
+        if self
+            .open_challengers
+            .contains_key(&(challenger, subject.clone()))
+        {
+            return Err(OperationError::ChallengeAlreadyOpen);
+        }
+
+        let open_challenges = self.get_open_challenge_count(&subject);
+
+        if open_challenges >= MAX_OPEN_CHALLENGES_PER_EDITION {
+            return Err(OperationError::ChallengeLimitReached);
+        }
+
+        let challenge = Challenge {
+            id: self.take_next_challenge_id(),
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            token_id,
+            subject: subject.clone(),
+            challenger,
+            evidence_hash,
+            resolution: None,
+        };
+        self.challenges.insert(challenge.id, challenge.clone());
+        self.open_challenges
+            .insert(subject.clone(), open_challenges + 1);
+        self.open_challengers
+            .insert((challenger, subject), challenge.id);
+
+        match self.edition_challenges.get_mut(&token_id) {
+            None => {
+                let mut challenge_ids = GenericVec::new();
+                challenge_ids.push(challenge.id);
+                self.edition_challenges.insert(token_id, challenge_ids);
+            }
+            Some(challenge_ids) => challenge_ids.push(challenge.id),
+        }
+
+        Ok(challenge)
+    }
+
+    pub fn resolve(
+        &mut self,
+        challenge_id: ChallengeId,
+        resolution: Resolution,
+    ) -> Result<Challenge, OperationError> {
+        match self.challenges.get_mut(&challenge_id) {
+            None => Err(OperationError::ChallengeNotFound),
+            Some(challenge) => {
+                if challenge.resolution.is_some() {
+                    return Err(OperationError::ChallengeAlreadyResolved);
+                }
+
+                challenge.resolution = Some(resolution);
+                let challenge = challenge.clone();
+                let open_challenges = self.get_open_challenge_count(&challenge.subject);
+                self.open_challenges
+                    .insert(challenge.subject.clone(), open_challenges.saturating_sub(1));
+                self.open_challengers
+                    .take(&(challenge.challenger, challenge.subject.clone()));
+
+                if resolution == Resolution::Upheld {
+                    self.upheld_challenges
+                        .insert(challenge.subject.clone(), challenge.id);
+                }
+
+                Ok(challenge)
+            }
+        }
+    }
+
+    pub fn get_by_id(&self, challenge_id: ChallengeId) -> Result<Challenge, OperationError> {
+        match self.challenges.get(&challenge_id) {
+            None => Err(OperationError::ChallengeNotFound),
+            Some(challenge) => Ok(challenge.clone()),
+        }
+    }
+
+    pub fn get_open_challenge_count(&self, subject: &Subject) -> u32 {
+        match self.open_challenges.get(subject) {
+            None => 0,
+            Some(open_challenges) => *open_challenges,
+        }
+    }
+
+    pub fn check_can_activate(&self, subject: &Subject) -> Result<(), OperationError> {
+        if self.upheld_challenges.contains_key(subject) {
+            return Err(OperationError::ChallengeUpheld);
+        }
+
+        if self.get_open_challenge_count(subject) > 0 {
+            return Err(OperationError::ChallengeOpen);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_edition_challenge_page(
+        &self,
+        token_id: TokenId,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Page<u32, Challenge> {
+        match self.edition_challenges.get(&token_id) {
+            None => Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            },
+            Some(challenge_ids) => {
+                paginate_indexed(challenge_ids.len() as u32, start, limit, |index| {
+                    self.challenges
+                        .get(challenge_ids.get(index as usize)?)
+                        .cloned()
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::String;
+    use ink_env::test::{run_test, DefaultAccounts};
+
+    #[test]
+    fn open_challenges_are_counted_per_edition() {
+        run_test::<DefaultEnvironment, _>(|accounts: DefaultAccounts<DefaultEnvironment>| {
+            let mut docket = Docket::default();
+            let evidence_hash = Hash::default();
+
+            let challenge = docket
+                .file(1, Subject::Edition(1), accounts.alice, evidence_hash)
+                .unwrap();
+            assert!(matches!(
+                docket.file(1, Subject::Edition(1), accounts.alice, evidence_hash),
+                Err(OperationError::ChallengeAlreadyOpen)
+            ));
+            docket
+                .file(1, Subject::Edition(1), accounts.bob, evidence_hash)
+                .unwrap();
+            docket
+                .file(2, Subject::Edition(2), accounts.alice, evidence_hash)
+                .unwrap();
+            docket
+                .file(
+                    1,
+                    Subject::Supplement(String::from("VCS-1/A")),
+                    accounts.alice,
+                    evidence_hash,
+                )
+                .unwrap();
+            assert_eq!(docket.get_open_challenge_count(&Subject::Edition(1)), 2);
+            assert!(matches!(
+                docket.check_can_activate(&Subject::Edition(1)),
+                Err(OperationError::ChallengeOpen)
+            ));
+
+            docket.resolve(challenge.id, Resolution::Dismissed).unwrap();
+            assert_eq!(docket.get_open_challenge_count(&Subject::Edition(1)), 1);
+            docket
+                .file(1, Subject::Edition(1), accounts.alice, evidence_hash)
+                .unwrap();
+            assert_eq!(docket.get_open_challenge_count(&Subject::Edition(1)), 2);
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn open_challenges_are_capped_per_edition() {
+        run_test::<DefaultEnvironment, _>(|_: DefaultAccounts<DefaultEnvironment>| {
+            let mut docket = Docket::default();
+
+            for challenger in 0..MAX_OPEN_CHALLENGES_PER_EDITION {
+                let challenger = AccountId::from([challenger as u8; 32]);
+                docket
+                    .file(1, Subject::Edition(1), challenger, Hash::default())
+                    .unwrap();
+            }
+
+            assert!(matches!(
+                docket.file(
+                    1,
+                    Subject::Edition(1),
+                    AccountId::from([0xff; 32]),
+                    Hash::default()
+                ),
+                Err(OperationError::ChallengeLimitReached)
+            ));
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,195 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
+use crate::{
+    CarbonUnit, Decode, Encode, OperationError, SnapshotId, SpreadLayout, StorageBox,
+    StorageHashMap, StorageVec, String, TokenId,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Checkpoint {
+    pub block_number: BlockNumber,
+    pub value: CarbonUnit,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Snapshot {
+    pub id: SnapshotId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub name: String,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct History {
+    next_snapshot_id: SnapshotId,
+    snapshots: StorageHashMap<SnapshotId, Snapshot>,
+    balances: StorageHashMap<(AccountId, TokenId), StorageBox<StorageVec<Checkpoint>>>,
+    supplies: StorageHashMap<TokenId, StorageBox<StorageVec<Checkpoint>>>,
+}
+
+fn write_checkpoint(checkpoints: &mut StorageVec<Checkpoint>, value: CarbonUnit) {
+    let current_block_number = block_number::<DefaultEnvironment>();
+    let checkpoint_count = checkpoints.len();
+
+    if checkpoint_count > 0 {
+        let last_checkpoint = checkpoints.get_mut(checkpoint_count - 1).unwrap();
+
+        if last_checkpoint.block_number == current_block_number {
+            last_checkpoint.value = value;
+            return;
+        }
+    }
+
+    checkpoints.push(Checkpoint {
+        block_number: current_block_number,
+        value,
+    });
+}
+
+fn read_checkpoint(checkpoints: &StorageVec<Checkpoint>, block: BlockNumber) -> CarbonUnit {
+    let mut low = 0;
+    let mut high = checkpoints.len();
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        if checkpoints.get(middle).unwrap().block_number <= block {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    if low == 0 {
+        return 0;
+    }
+
+    checkpoints.get(low - 1).unwrap().value
+}
+
+impl History {
+    pub fn take_next_snapshot_id(&mut self) -> SnapshotId {
+        let next_snapshot_id = self.next_snapshot_id;
+        self.next_snapshot_id += 1;
+
+        next_snapshot_id
+    }
+
+    pub fn record_balance(
+        &mut self,
+        account_id: AccountId,
+        token_id: TokenId,
+        balance: CarbonUnit,
+    ) {
+        let key = (account_id, token_id);
+
+        if !self.balances.contains_key(&key) {
+            self.balances
+                .insert(key, StorageBox::new(StorageVec::new()));
+        }
+
+        write_checkpoint(self.balances.get_mut(&key).unwrap(), balance);
+    }
+
+    pub fn record_supply(&mut self, token_id: TokenId, supply: CarbonUnit) {
+        if !self.supplies.contains_key(&token_id) {
+            self.supplies
+                .insert(token_id, StorageBox::new(StorageVec::new()));
+        }
+
+        write_checkpoint(self.supplies.get_mut(&token_id).unwrap(), supply);
+    }
+
+    pub fn get_balance_at(
+        &self,
+        account_id: AccountId,
+        token_id: TokenId,
+        block: BlockNumber,
+    ) -> CarbonUnit {
+        match self.balances.get(&(account_id, token_id)) {
+            None => 0,
+            Some(checkpoints) => read_checkpoint(checkpoints, block),
+        }
+    }
+
+    pub fn get_supply_at(&self, token_id: TokenId, block: BlockNumber) -> CarbonUnit {
+        match self.supplies.get(&token_id) {
+            None => 0,
+            Some(checkpoints) => read_checkpoint(checkpoints, block),
+        }
+    }
+
+    pub fn create_snapshot(&mut self, name: String) -> SnapshotId {
+        let snapshot_id = self.take_next_snapshot_id();
+        self.snapshots.insert(
+            snapshot_id,
+            Snapshot {
+                id: snapshot_id,
+                block_number: block_number::<DefaultEnvironment>(),
+                timestamp: block_timestamp::<DefaultEnvironment>(),
+                name,
+            },
+        );
+
+        snapshot_id
+    }
+
+    pub fn get_snapshot_by_id(&self, snapshot_id: SnapshotId) -> Result<Snapshot, OperationError> {
+        match self.snapshots.get(&snapshot_id) {
+            None => Err(OperationError::SnapshotNotFound),
+            Some(snapshot) => Ok(snapshot.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoints(entries: &[(BlockNumber, CarbonUnit)]) -> StorageVec<Checkpoint> {
+        let mut checkpoints = StorageVec::new();
+
+        for (block_number, value) in entries {
+            checkpoints.push(Checkpoint {
+                block_number: *block_number,
+                value: *value,
+            });
+        }
+
+        checkpoints
+    }
+
+    #[test]
+    fn read_checkpoint_before_first_block_is_zero() {
+        let checkpoints = checkpoints(&[(10, 5), (20, 7)]);
+
+        assert_eq!(read_checkpoint(&checkpoints, 0), 0);
+        assert_eq!(read_checkpoint(&checkpoints, 9), 0);
+    }
+
+    #[test]
+    fn read_checkpoint_at_stored_blocks() {
+        let checkpoints = checkpoints(&[(10, 5), (20, 7), (30, 2)]);
+
+        assert_eq!(read_checkpoint(&checkpoints, 10), 5);
+        assert_eq!(read_checkpoint(&checkpoints, 20), 7);
+        assert_eq!(read_checkpoint(&checkpoints, 30), 2);
+    }
+
+    #[test]
+    fn read_checkpoint_between_and_after_stored_blocks() {
+        let checkpoints = checkpoints(&[(10, 5), (20, 7), (30, 2)]);
+
+        assert_eq!(read_checkpoint(&checkpoints, 15), 5);
+        assert_eq!(read_checkpoint(&checkpoints, 29), 7);
+        assert_eq!(read_checkpoint(&checkpoints, BlockNumber::MAX), 2);
+    }
+
+    #[test]
+    fn read_checkpoint_without_entries_is_zero() {
+        assert_eq!(read_checkpoint(&checkpoints(&[]), 100), 0);
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{Decode, Encode};
+
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Key {
+    BufferRate,
+    ChallengePeriod,
+    CustodianBond,
+    LedgerRetention,
+    MintRequestTtl,
+    VerifierThreshold,
+}
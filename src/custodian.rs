@@ -1,27 +1,130 @@
-use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
-use crate::{Decode, Encode, GenericVec, OperationError, SpreadLayout, StorageHashMap, String};
+use crate::environment::{AccountId, Balance, BlockNumber, DefaultEnvironment, Timestamp};
+use crate::pagination::{KeyIndex, Page};
+use crate::{
+    CarbonUnit, Decode, Encode, GenericVec, OperationError, RegistryId, SpreadLayout,
+    StorageHashMap, String, TokenId, Year,
+};
 use ink_env::{block_number, block_timestamp};
 use ink_storage::traits::PackedLayout;
 
+pub const MAX_SLASH_PAYOUTS: u32 = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Status {
+    Active,
+    Suspended,
+    Revoked,
+}
+
+#[derive(Clone, Debug, Default, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Scope {
+    pub registries: GenericVec<String>,
+    pub years: GenericVec<Year>,
+}
+
+#[derive(Clone, Debug, Default, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Quota {
+    pub max_per_request: Option<CarbonUnit>,
+    pub max_per_window: Option<CarbonUnit>,
+    pub window_blocks: BlockNumber,
+    pub max_pending: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Usage {
+    pub pending: u32,
+    pub recent_requests: GenericVec<(BlockNumber, CarbonUnit)>,
+}
+
+#[derive(Clone, Debug, Default, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Stats {
+    pub requests_submitted: u32,
+    pub requests_approved: u32,
+    pub requests_denied: u32,
+    pub requests_expired: u32,
+    pub requests_withdrawn: u32,
+    pub tonnes_minted: CarbonUnit,
+    pub tonnes_cancelled: CarbonUnit,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum RequestOutcome {
+    Approved(CarbonUnit),
+    Denied,
+    Expired,
+    Withdrawn,
+}
+
+#[derive(Clone, Debug, Default, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Bond {
+    pub bonded: Balance,
+    pub unbonding: Balance,
+    pub unbonding_at: Option<BlockNumber>,
+}
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct BondConfig {
+    pub requirement: Balance,
+    pub unbonding_period: BlockNumber,
+    pub treasury: Option<AccountId>,
+}
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum SlashTarget {
+    Treasury,
+    /// Pays the cancelled holders of an invalidated edition minted by the custodian.
+    Holders(TokenId),
+}
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RemainingQuota {
+    pub per_request: Option<CarbonUnit>,
+    pub window: Option<CarbonUnit>,
+    pub pending: Option<u32>,
+}
+
 #[derive(Clone, Debug, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct AddParams {
     pub id: AccountId,
     pub alias: String,
+    pub expiry: Option<BlockNumber>,
+    pub scope: Scope,
 }
 
 #[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct Detail {
-    id: AccountId,
-    block_number: BlockNumber,
-    timestamp: Timestamp,
-    alias: String,
+    pub id: AccountId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub alias: String,
+    pub status: Status,
+    pub expiry: Option<BlockNumber>,
+    pub scope: Scope,
+    pub quota: Quota,
+    pub usage: Usage,
+    pub stats: Stats,
+    pub bond: Bond,
 }
 
 #[derive(Debug, Default, SpreadLayout)]
 pub struct Collections {
     custodians: StorageHashMap<AccountId, Detail>,
+    custodian_ids: KeyIndex<AccountId>,
+    bond_requirement: Balance,
+    unbonding_period: BlockNumber,
+    treasury: Option<AccountId>,
 }
 
 impl From<AddParams> for Detail {
@@ -31,6 +134,107 @@ impl From<AddParams> for Detail {
             alias: source.alias,
             block_number: block_number::<DefaultEnvironment>(),
             timestamp: block_timestamp::<DefaultEnvironment>(),
+            status: Status::Active,
+            expiry: source.expiry,
+            scope: source.scope,
+            quota: Quota::default(),
+            usage: Usage::default(),
+            stats: Stats::default(),
+            bond: Bond::default(),
+        }
+    }
+}
+
+impl Scope {
+    pub fn permits(&self, registry_id: &RegistryId, year: Year) -> bool {
+        let registry_permitted = self.registries.is_empty()
+            || self
+                .registries
+                .iter()
+                .any(|registry| is_registry_prefix(registry, registry_id));
+        let year_permitted = self.years.is_empty() || self.years.contains(&year);
+
+        registry_permitted && year_permitted
+    }
+}
+
+fn is_registry_prefix(registry: &str, registry_id: &str) -> bool {
+    if registry.is_empty() {
+        return false;
+    }
+
+    match registry_id.strip_prefix(registry) {
+        None => false,
+        Some(rest) => rest.is_empty() || rest.starts_with(|c: char| !c.is_ascii_alphanumeric()),
+    }
+}
+
+impl Usage {
+    pub fn get_window_usage(&self, window_blocks: BlockNumber) -> CarbonUnit {
+        let current_block_number = block_number::<DefaultEnvironment>();
+        let mut window_usage = 0;
+
+        for (request_block_number, amount) in &self.recent_requests {
+            if request_block_number.saturating_add(window_blocks) > current_block_number {
+                window_usage += *amount;
+            }
+        }
+
+        window_usage
+    }
+}
+
+impl Detail {
+    pub fn get_remaining_quota(&self) -> RemainingQuota {
+        RemainingQuota {
+            per_request: self.quota.max_per_request,
+            window: self.quota.max_per_window.map(|max_per_window| {
+                max_per_window.saturating_sub(self.usage.get_window_usage(self.quota.window_blocks))
+            }),
+            pending: self
+                .quota
+                .max_pending
+                .map(|max_pending| max_pending.saturating_sub(self.usage.pending)),
+        }
+    }
+
+    pub fn check_quota(&self, amount: CarbonUnit) -> Result<(), OperationError> {
+        let remaining_quota = self.get_remaining_quota();
+
+        if let Some(max_per_request) = remaining_quota.per_request {
+            if amount > max_per_request {
+                return Err(OperationError::CustodianRequestQuotaExceeded);
+            }
+        }
+
+        if let Some(remaining_window) = remaining_quota.window {
+            if amount > remaining_window {
+                return Err(OperationError::CustodianWindowQuotaExceeded);
+            }
+        }
+
+        if let Some(remaining_pending) = remaining_quota.pending {
+            if remaining_pending == 0 {
+                return Err(OperationError::CustodianPendingLimitReached);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_expired(&self) -> bool {
+        match self.expiry {
+            None => false,
+            Some(expiry) => block_number::<DefaultEnvironment>() >= expiry,
+        }
+    }
+
+    pub fn check_standing(&self) -> Result<(), OperationError> {
+        match self.status {
+            Status::Revoked => Err(OperationError::CustodianRevoked),
+            Status::Suspended => Err(OperationError::CustodianSuspended),
+            Status::Active if self.is_expired() => Err(OperationError::CustodianExpired),
+            Status::Active => Ok(()),
         }
     }
 }
@@ -40,25 +244,280 @@ impl Collections {
         self.custodians.contains_key(&id)
     }
 
-    pub fn add(&mut self, create_params: AddParams) -> Result<(), OperationError> {
+    pub fn get_by_id(&self, id: AccountId) -> Result<Detail, OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => Ok(detail.clone()),
+        }
+    }
+
+    pub fn check_can_mint(
+        &self,
+        id: AccountId,
+        registry_id: &RegistryId,
+        year: Year,
+        amount: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::Unauthorized),
+            Some(detail) => {
+                detail.check_standing()?;
+
+                if !detail.scope.permits(registry_id, year) {
+                    return Err(OperationError::CustodianScopeViolation);
+                }
+
+                if detail.bond.bonded < self.bond_requirement {
+                    return Err(OperationError::CustodianBondInsufficient);
+                }
+
+                detail.check_quota(amount)
+            }
+        }
+    }
+
+    pub fn record_request(&mut self, id: AccountId, amount: CarbonUnit) {
+        if let Some(detail) = self.custodians.get_mut(&id) {
+            let current_block_number = block_number::<DefaultEnvironment>();
+            let window_blocks = detail.quota.window_blocks;
+            detail
+                .usage
+                .recent_requests
+                .retain(|(request_block_number, _)| {
+                    request_block_number.saturating_add(window_blocks) > current_block_number
+                });
+            detail
+                .usage
+                .recent_requests
+                .push((current_block_number, amount));
+            detail.usage.pending += 1;
+            detail.stats.requests_submitted += 1;
+        }
+    }
+
+    pub fn close_request(&mut self, id: AccountId, outcome: RequestOutcome) {
+        if let Some(detail) = self.custodians.get_mut(&id) {
+            detail.usage.pending = detail.usage.pending.saturating_sub(1);
+
+            match outcome {
+                RequestOutcome::Approved(amount) => {
+                    detail.stats.requests_approved += 1;
+                    detail.stats.tonnes_minted += amount;
+                }
+                RequestOutcome::Denied => detail.stats.requests_denied += 1,
+                RequestOutcome::Expired => detail.stats.requests_expired += 1,
+                RequestOutcome::Withdrawn => detail.stats.requests_withdrawn += 1,
+            }
+        }
+    }
+
+    pub fn record_cancellation(&mut self, id: AccountId, amount: CarbonUnit) {
+        if let Some(detail) = self.custodians.get_mut(&id) {
+            detail.stats.tonnes_cancelled += amount;
+        }
+    }
+
+    pub fn get_stats(&self, id: AccountId) -> Result<Stats, OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => Ok(detail.stats.clone()),
+        }
+    }
+
+    pub fn set_quota(&mut self, id: AccountId, quota: Quota) -> Result<Detail, OperationError> {
+        if quota.max_per_window.is_some() && quota.window_blocks == 0 {
+            return Err(OperationError::InvalidCustodianQuota);
+        }
+
+        match self.custodians.get_mut(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => {
+                detail.quota = quota;
+
+                Ok(detail.clone())
+            }
+        }
+    }
+
+    pub fn get_remaining_quota(&self, id: AccountId) -> Result<RemainingQuota, OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => Ok(detail.get_remaining_quota()),
+        }
+    }
+
+    pub fn check_active(&self, id: AccountId) -> Result<(), OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::Unauthorized),
+            Some(detail) => detail.check_standing(),
+        }
+    }
+
+    pub fn check_can_approve(&self, id: AccountId) -> Result<(), OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::CustodianRevoked),
+            Some(detail) => detail.check_standing(),
+        }
+    }
+
+    pub fn add(&mut self, create_params: AddParams) -> Result<Detail, OperationError> {
         if self.custodians.contains_key(&create_params.id) {
             return Err(OperationError::CustodianAlreadyRegistered);
         }
 
-        self.custodians
-            .insert(create_params.id, create_params.into());
+        let detail: Detail = create_params.into();
+        self.custodians.insert(detail.id, detail.clone());
+        self.custodian_ids.insert(detail.id);
 
-        Ok(())
+        Ok(detail)
     }
 
-    pub fn remove(&mut self, id: AccountId) -> Result<(), OperationError> {
-        if !self.custodians.contains_key(&id) {
-            return Err(OperationError::CustodianNotFound);
+    pub fn remove(&mut self, id: AccountId) -> Result<Detail, OperationError> {
+        match self.custodians.get(&id) {
+            None => return Err(OperationError::CustodianNotFound),
+            Some(detail) => {
+                if detail.bond.bonded > 0 || detail.bond.unbonding > 0 {
+                    return Err(OperationError::CustodianBondOutstanding);
+                }
+            }
         }
 
-        self.custodians.take(&id);
+        self.custodian_ids.remove(&id);
 
-        Ok(())
+        Ok(self.custodians.take(&id).unwrap())
+    }
+
+    pub fn set_status(&mut self, id: AccountId, status: Status) -> Result<Detail, OperationError> {
+        match self.custodians.get_mut(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => {
+                if detail.status == Status::Revoked && status != Status::Revoked {
+                    return Err(OperationError::CustodianRevoked);
+                }
+
+                detail.status = status;
+
+                Ok(detail.clone())
+            }
+        }
+    }
+
+    pub fn set_scope(
+        &mut self,
+        id: AccountId,
+        expiry: Option<BlockNumber>,
+        scope: Scope,
+    ) -> Result<Detail, OperationError> {
+        match self.custodians.get_mut(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => {
+                detail.expiry = expiry;
+                detail.scope = scope;
+
+                Ok(detail.clone())
+            }
+        }
+    }
+
+    pub fn get_bond_config(&self) -> BondConfig {
+        BondConfig {
+            requirement: self.bond_requirement,
+            unbonding_period: self.unbonding_period,
+            treasury: self.treasury,
+        }
+    }
+
+    pub fn set_bond_config(&mut self, config: BondConfig) {
+        self.bond_requirement = config.requirement;
+        self.unbonding_period = config.unbonding_period;
+        self.treasury = config.treasury;
+    }
+
+    pub fn get_treasury(&self) -> Result<AccountId, OperationError> {
+        match self.treasury {
+            None => Err(OperationError::TreasuryNotConfigured),
+            Some(treasury) => Ok(treasury),
+        }
+    }
+
+    pub fn get_bond(&self, id: AccountId) -> Result<Bond, OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => Ok(detail.bond.clone()),
+        }
+    }
+
+    pub fn deposit_bond(&mut self, id: AccountId, amount: Balance) -> Result<Bond, OperationError> {
+        match self.custodians.get_mut(&id) {
+            None => Err(OperationError::Unauthorized),
+            Some(detail) => {
+                detail.bond.bonded += amount;
+
+                Ok(detail.bond.clone())
+            }
+        }
+    }
+
+    pub fn unbond(&mut self, id: AccountId, amount: Balance) -> Result<Bond, OperationError> {
+        let unbonding_period = self.unbonding_period;
+
+        match self.custodians.get_mut(&id) {
+            None => Err(OperationError::Unauthorized),
+            Some(detail) => {
+                if detail.bond.bonded < amount {
+                    return Err(OperationError::CustodianBondInsufficient);
+                }
+
+                detail.bond.bonded -= amount;
+                detail.bond.unbonding += amount;
+                detail.bond.unbonding_at =
+                    Some(block_number::<DefaultEnvironment>().saturating_add(unbonding_period));
+
+                Ok(detail.bond.clone())
+            }
+        }
+    }
+
+    pub fn get_withdrawable_bond(&self, id: AccountId) -> Result<Balance, OperationError> {
+        match self.custodians.get(&id) {
+            None => Err(OperationError::Unauthorized),
+            Some(detail) => match detail.bond.unbonding_at {
+                Some(unbonding_at) if block_number::<DefaultEnvironment>() >= unbonding_at => {
+                    Ok(detail.bond.unbonding)
+                }
+                _ => Err(OperationError::CustodianBondLocked),
+            },
+        }
+    }
+
+    pub fn release_unbonded(&mut self, id: AccountId) -> Result<Balance, OperationError> {
+        let withdrawable = self.get_withdrawable_bond(id)?;
+        let detail = self.custodians.get_mut(&id).unwrap();
+        detail.bond.unbonding = 0;
+        detail.bond.unbonding_at = None;
+
+        Ok(withdrawable)
+    }
+
+    pub fn slash_bond(&mut self, id: AccountId, amount: Balance) -> Result<Bond, OperationError> {
+        match self.custodians.get_mut(&id) {
+            None => Err(OperationError::CustodianNotFound),
+            Some(detail) => {
+                if detail.bond.bonded.saturating_add(detail.bond.unbonding) < amount {
+                    return Err(OperationError::CustodianBondInsufficient);
+                }
+
+                let slashed_bonded = detail.bond.bonded.min(amount);
+                detail.bond.bonded -= slashed_bonded;
+                detail.bond.unbonding -= amount - slashed_bonded;
+
+                if detail.bond.unbonding == 0 {
+                    detail.bond.unbonding_at = None;
+                }
+
+                Ok(detail.bond.clone())
+            }
+        }
     }
 
     pub fn list(&self) -> GenericVec<Detail> {
@@ -67,4 +526,9 @@ impl Collections {
             .cloned()
             .collect::<GenericVec<Detail>>()
     }
+
+    pub fn list_page(&self, start: Option<u32>, limit: u32) -> Page<u32, Detail> {
+        self.custodian_ids
+            .get_page(start, limit, |id| self.custodians.get(id).cloned())
+    }
 }
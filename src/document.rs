@@ -0,0 +1,138 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Hash, Timestamp};
+use crate::pagination::{paginate_indexed, Page};
+use crate::{
+    Decode, DocumentVersion, Encode, GenericVec, OperationError, ProjectId, SpreadLayout,
+    StorageBox, StorageHashMap, StorageVec, String, TokenId,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+pub const MAX_DOCUMENTS_PER_SUBMISSION: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Kind {
+    VerificationReport,
+    IssuanceStatement,
+    RegistryCertificate,
+    ProjectDesign,
+    MonitoringReport,
+    Other(String),
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, SpreadLayout, PackedLayout, Encode, Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Subject {
+    Edition(TokenId),
+    Project(ProjectId),
+}
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AttachParams {
+    pub kind: Kind,
+    pub uri: String,
+    pub hash: Hash,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Document {
+    pub version: DocumentVersion,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub submitter: AccountId,
+    pub kind: Kind,
+    pub uri: String,
+    pub hash: Hash,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Archive {
+    documents: StorageHashMap<Subject, StorageBox<StorageVec<Document>>>,
+    versions: StorageHashMap<Subject, DocumentVersion>,
+}
+
+impl Archive {
+    pub fn check_params(&self, params: &[AttachParams]) -> Result<(), OperationError> {
+        if params.len() > MAX_DOCUMENTS_PER_SUBMISSION
+            || params.iter().any(|document| document.uri.is_empty())
+        {
+            return Err(OperationError::InvalidDocument);
+        }
+
+        Ok(())
+    }
+
+    pub fn append(
+        &mut self,
+        subject: Subject,
+        submitter: AccountId,
+        params: GenericVec<AttachParams>,
+    ) -> Result<GenericVec<Document>, OperationError> {
+        self.check_params(&params)?;
+
+        if params.is_empty() {
+            return Ok(GenericVec::new());
+        }
+
+        let version = self.get_version(subject) + 1;
+        self.versions.insert(subject, version);
+
+        if !self.documents.contains_key(&subject) {
+            self.documents
+                .insert(subject, StorageBox::new(StorageVec::new()));
+        }
+
+        let subject_documents = self.documents.get_mut(&subject).unwrap();
+        let mut anchored_documents = GenericVec::new();
+
+        for document in params {
+            let document = Document {
+                version,
+                block_number: block_number::<DefaultEnvironment>(),
+                timestamp: block_timestamp::<DefaultEnvironment>(),
+                submitter,
+                kind: document.kind,
+                uri: document.uri,
+                hash: document.hash,
+            };
+            subject_documents.push(document.clone());
+            anchored_documents.push(document);
+        }
+
+        Ok(anchored_documents)
+    }
+
+    pub fn clear(&mut self, subject: Subject) {
+        self.documents.take(&subject);
+    }
+
+    pub fn get_version(&self, subject: Subject) -> DocumentVersion {
+        match self.versions.get(&subject) {
+            None => 0,
+            Some(version) => *version,
+        }
+    }
+
+    pub fn get_page(
+        &self,
+        subject: Subject,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Page<u32, Document> {
+        match self.documents.get(&subject) {
+            None => Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            },
+            Some(subject_documents) => {
+                paginate_indexed(subject_documents.len(), start, limit, |index| {
+                    subject_documents.get(index).cloned()
+                })
+            }
+        }
+    }
+}
@@ -3,15 +3,70 @@ use scale::{Decode, Encode};
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum Message {
+    BatchTransferFailed(u32),
     BlockchainCorrupted,
+    BondTransferFailed,
+    BufferCoverageExceeded,
+    BufferCoverageNotRequired,
     CannotTransferZeroCarbonUnit,
+    ChallengeAlreadyOpen,
+    ChallengeAlreadyResolved,
+    ChallengeLimitReached,
+    ChallengeNotFound,
+    ChallengeOpen,
+    ChallengePeriodElapsed,
+    ChallengeUpheld,
     CustodianAlreadyRegistered,
+    CustodianBondInsufficient,
+    CustodianBondLocked,
+    CustodianBondOutstanding,
+    CustodianExpired,
     CustodianNotFound,
+    CustodianPendingLimitReached,
+    CustodianRequestQuotaExceeded,
+    CustodianRevoked,
+    CustodianScopeViolation,
+    CustodianSlashPayoutsExceeded,
+    CustodianSlashTargetInvalid,
+    CustodianSuspended,
+    CustodianWindowQuotaExceeded,
     InsufficientCarbonUnit,
+    InsufficientPoolBacking,
+    InsufficientPoolUnit,
+    InvalidBufferRate,
+    InvalidCreditAttributes,
+    InvalidCustodianQuota,
+    InvalidDocument,
+    InvalidPoolParameters,
+    InvalidSerialRange,
+    LedgerEntryNotFound,
+    PoolNotFound,
+    ProjectNotApproved,
+    ProjectNotFound,
+    ProjectNotPending,
+    ReceiverRejected,
     RetirementReportNotFound,
+    SnapshotNotFound,
+    TokenActivationPending,
     TokenAlreadyMinted,
+    TokenFrozen,
+    TokenInvalidated,
+    TokenInvalidationComplete,
+    TokenInvalidationPending,
     TokenMintRequestAlreadyPending,
+    TokenMintRequestExpired,
+    TokenMintRequestNotExpired,
     TokenMintRequestNotFound,
+    TokenNotActive,
+    TokenNotEligibleForPool,
     TokenNotFound,
+    TokenNotInvalidated,
+    TokenNotPendingActivation,
+    TreasuryNotConfigured,
     Unauthorized,
+    VerifierAlreadyAttested,
+    VerifierAlreadyRegistered,
+    VerifierAttestationsInsufficient,
+    VerifierNotFound,
+    VerifierNotIndependent,
 }
@@ -0,0 +1,394 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
+use crate::pagination::{clamp_limit, Page};
+use crate::{
+    Decode, Encode, GenericVec, LedgerSequence, OperationError, SpreadLayout, StorageHashMap,
+    TokenEditions, TokenId,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+pub const MAX_PRUNED_ENTRIES_PER_RECORD: u32 = 8;
+
+#[derive(Clone, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum EntryKind {
+    Mint,
+    Transfer,
+    Retirement,
+    Cancellation,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Entry {
+    pub sequence: LedgerSequence,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub kind: EntryKind,
+    pub from: AccountId,
+    pub to: AccountId,
+    pub editions: TokenEditions,
+}
+
+#[derive(Clone, Copy, Debug, Default, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Queue {
+    pub head: u32,
+    pub tail: u32,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Journal {
+    next_sequence: LedgerSequence,
+    first_retained_sequence: LedgerSequence,
+    retention_blocks: Option<BlockNumber>,
+    entries: StorageHashMap<LedgerSequence, Entry>,
+    account_queues: StorageHashMap<AccountId, Queue>,
+    account_sequences: StorageHashMap<(AccountId, u32), LedgerSequence>,
+    edition_queues: StorageHashMap<TokenId, Queue>,
+    edition_sequences: StorageHashMap<(TokenId, u32), LedgerSequence>,
+}
+
+impl Journal {
+    pub fn take_next_sequence(&mut self) -> LedgerSequence {
+        let next_sequence = self.next_sequence;
+        self.next_sequence += 1;
+
+        next_sequence
+    }
+
+    pub fn get_retention(&self) -> Option<BlockNumber> {
+        self.retention_blocks
+    }
+
+    pub fn set_retention(&mut self, retention_blocks: Option<BlockNumber>) {
+        self.retention_blocks = retention_blocks;
+    }
+
+    pub fn record(
+        &mut self,
+        kind: EntryKind,
+        from: AccountId,
+        to: AccountId,
+        editions: &TokenEditions,
+    ) -> LedgerSequence {
+        let sequence = self.take_next_sequence();
+        let entry = Entry {
+            sequence,
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            kind,
+            from,
+            to,
+            editions: editions.clone(),
+        };
+        self.entries.insert(sequence, entry);
+        self.index_account(from, sequence);
+
+        if to != from {
+            self.index_account(to, sequence);
+        }
+
+        for token_edition in editions {
+            self.index_edition(token_edition.id, sequence);
+        }
+
+        self.prune();
+
+        sequence
+    }
+
+    pub fn get_entry_by_sequence(&self, sequence: LedgerSequence) -> Result<Entry, OperationError> {
+        match self.entries.get(&sequence) {
+            None => Err(OperationError::LedgerEntryNotFound),
+            Some(entry) => Ok(entry.clone()),
+        }
+    }
+
+    pub fn get_account_entry_page(
+        &self,
+        account_id: AccountId,
+        start: Option<LedgerSequence>,
+        limit: u32,
+    ) -> Page<LedgerSequence, Entry> {
+        match self.account_queues.get(&account_id) {
+            None => Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            },
+            Some(queue) => self.get_queue_page(*queue, start, limit, |position| {
+                *self.account_sequences.get(&(account_id, position)).unwrap()
+            }),
+        }
+    }
+
+    pub fn get_edition_entry_page(
+        &self,
+        token_id: TokenId,
+        start: Option<LedgerSequence>,
+        limit: u32,
+    ) -> Page<LedgerSequence, Entry> {
+        match self.edition_queues.get(&token_id) {
+            None => Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            },
+            Some(queue) => self.get_queue_page(*queue, start, limit, |position| {
+                *self.edition_sequences.get(&(token_id, position)).unwrap()
+            }),
+        }
+    }
+
+    fn get_queue_page<F>(
+        &self,
+        queue: Queue,
+        start: Option<LedgerSequence>,
+        limit: u32,
+        sequence_at: F,
+    ) -> Page<LedgerSequence, Entry>
+    where
+        F: Fn(u32) -> LedgerSequence,
+    {
+        let start = start.unwrap_or(0);
+        let mut low = queue.head;
+        let mut high = queue.tail;
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if sequence_at(middle) < start {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        let page_end = low.saturating_add(clamp_limit(limit)).min(queue.tail);
+        let items = (low..page_end)
+            .filter_map(|position| self.entries.get(&sequence_at(position)).cloned())
+            .collect::<GenericVec<Entry>>();
+        let next_cursor = if page_end < queue.tail {
+            Some(sequence_at(page_end))
+        } else {
+            None
+        };
+
+        Page { items, next_cursor }
+    }
+
+    fn index_account(&mut self, account_id: AccountId, sequence: LedgerSequence) {
+        let mut queue = self
+            .account_queues
+            .get(&account_id)
+            .copied()
+            .unwrap_or_default();
+        self.account_sequences
+            .insert((account_id, queue.tail), sequence);
+        queue.tail += 1;
+        self.account_queues.insert(account_id, queue);
+    }
+
+    fn index_edition(&mut self, token_id: TokenId, sequence: LedgerSequence) {
+        let mut queue = self
+            .edition_queues
+            .get(&token_id)
+            .copied()
+            .unwrap_or_default();
+        self.edition_sequences
+            .insert((token_id, queue.tail), sequence);
+        queue.tail += 1;
+        self.edition_queues.insert(token_id, queue);
+    }
+
+    fn unindex_account(&mut self, account_id: AccountId, sequence: LedgerSequence) {
+        let mut queue = match self.account_queues.get(&account_id) {
+            None => return,
+            Some(queue) => *queue,
+        };
+
+        if self.account_sequences.get(&(account_id, queue.head)) != Some(&sequence) {
+            return;
+        }
+
+        self.account_sequences.take(&(account_id, queue.head));
+        queue.head += 1;
+
+        if queue.head == queue.tail {
+            self.account_queues.take(&account_id);
+        } else {
+            self.account_queues.insert(account_id, queue);
+        }
+    }
+
+    fn unindex_edition(&mut self, token_id: TokenId, sequence: LedgerSequence) {
+        let mut queue = match self.edition_queues.get(&token_id) {
+            None => return,
+            Some(queue) => *queue,
+        };
+
+        if self.edition_sequences.get(&(token_id, queue.head)) != Some(&sequence) {
+            return;
+        }
+
+        self.edition_sequences.take(&(token_id, queue.head));
+        queue.head += 1;
+
+        if queue.head == queue.tail {
+            self.edition_queues.take(&token_id);
+        } else {
+            self.edition_queues.insert(token_id, queue);
+        }
+    }
+
+    fn prune(&mut self) {
+        let retention_blocks = match self.retention_blocks {
+            None => return,
+            Some(retention_blocks) => retention_blocks,
+        };
+        let cutoff = block_number::<DefaultEnvironment>().saturating_sub(retention_blocks);
+        let mut pruned_entries = 0;
+
+        while pruned_entries < MAX_PRUNED_ENTRIES_PER_RECORD
+            && self.first_retained_sequence < self.next_sequence
+        {
+            let sequence = self.first_retained_sequence;
+            let is_retained = match self.entries.get(&sequence) {
+                None => false,
+                Some(entry) => entry.block_number >= cutoff,
+            };
+
+            if is_retained {
+                break;
+            }
+
+            if let Some(entry) = self.entries.take(&sequence) {
+                self.unindex_account(entry.from, sequence);
+
+                if entry.to != entry.from {
+                    self.unindex_account(entry.to, sequence);
+                }
+
+                for token_edition in &entry.editions {
+                    self.unindex_edition(token_edition.id, sequence);
+                }
+            }
+
+            self.first_retained_sequence += 1;
+            pruned_entries += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenEdition;
+    use ink_env::test::{advance_block, run_test, DefaultAccounts};
+
+    fn editions(token_id: TokenId) -> TokenEditions {
+        let mut editions = GenericVec::new();
+        editions.push(TokenEdition {
+            id: token_id,
+            amount: 1,
+        });
+
+        editions
+    }
+
+    fn sequences(page: &Page<LedgerSequence, Entry>) -> GenericVec<LedgerSequence> {
+        page.items.iter().map(|entry| entry.sequence).collect()
+    }
+
+    #[test]
+    fn account_page_cursor_stops_at_last_page() {
+        run_test::<DefaultEnvironment, _>(|accounts: DefaultAccounts<DefaultEnvironment>| {
+            let mut journal = Journal::default();
+
+            for _ in 0..5 {
+                journal.record(
+                    EntryKind::Transfer,
+                    accounts.alice,
+                    accounts.bob,
+                    &editions(1),
+                );
+            }
+
+            let first_page = journal.get_account_entry_page(accounts.bob, None, 3);
+            assert_eq!(sequences(&first_page), vec![0, 1, 2]);
+            assert_eq!(first_page.next_cursor, Some(3));
+
+            let last_page = journal.get_account_entry_page(accounts.bob, first_page.next_cursor, 3);
+            assert_eq!(sequences(&last_page), vec![3, 4]);
+            assert_eq!(last_page.next_cursor, None);
+
+            let exact_page = journal.get_edition_entry_page(1, Some(2), 3);
+            assert_eq!(sequences(&exact_page), vec![2, 3, 4]);
+            assert_eq!(exact_page.next_cursor, None);
+
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn prune_is_bounded_per_record_and_unindexes_entries() {
+        run_test::<DefaultEnvironment, _>(|accounts: DefaultAccounts<DefaultEnvironment>| {
+            let mut journal = Journal::default();
+            let expired_entries = MAX_PRUNED_ENTRIES_PER_RECORD as LedgerSequence + 4;
+
+            for _ in 0..expired_entries {
+                journal.record(EntryKind::Mint, accounts.alice, accounts.bob, &editions(1));
+            }
+
+            journal.set_retention(Some(1));
+            advance_block::<DefaultEnvironment>()?;
+            advance_block::<DefaultEnvironment>()?;
+            let sequence = journal.record(
+                EntryKind::Transfer,
+                accounts.bob,
+                accounts.charlie,
+                &editions(2),
+            );
+
+            assert_eq!(
+                journal
+                    .get_entry_by_sequence(MAX_PRUNED_ENTRIES_PER_RECORD as LedgerSequence - 1)
+                    .unwrap_err(),
+                OperationError::LedgerEntryNotFound
+            );
+            assert!(journal
+                .get_entry_by_sequence(MAX_PRUNED_ENTRIES_PER_RECORD as LedgerSequence)
+                .is_ok());
+
+            let page = journal.get_account_entry_page(accounts.alice, None, 100);
+            assert_eq!(
+                sequences(&page),
+                (MAX_PRUNED_ENTRIES_PER_RECORD as LedgerSequence..expired_entries)
+                    .collect::<GenericVec<_>>()
+            );
+
+            journal.record(
+                EntryKind::Transfer,
+                accounts.bob,
+                accounts.charlie,
+                &editions(2),
+            );
+
+            assert!(journal
+                .get_account_entry_page(accounts.alice, None, 100)
+                .items
+                .is_empty());
+            assert!(journal
+                .get_edition_entry_page(1, None, 100)
+                .items
+                .is_empty());
+            assert_eq!(
+                sequences(&journal.get_edition_entry_page(2, None, 100)),
+                vec![sequence, sequence + 1]
+            );
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
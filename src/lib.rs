@@ -2,16 +2,36 @@
 #![allow(clippy::nonminimal_bool)]
 #![allow(clippy::vec_init_then_push)]
 
+pub mod attribute;
+pub mod buffer;
+pub mod challenge;
+pub mod checkpoint;
+pub mod configuration;
 pub mod custodian;
+pub mod document;
 pub mod environment;
 pub mod error;
+pub mod ledger;
+pub mod pagination;
+pub mod pool;
+pub mod project;
+pub mod receiver;
 pub mod retirement;
+pub mod serial;
 pub mod token;
 pub mod utils;
+pub mod verifier;
 
+pub use crate::checkpoint::Snapshot as TokenSnapshot;
+pub use crate::configuration::Key as ConfigurationKey;
 pub use crate::error::Message as OperationError;
+pub use crate::ledger::Entry as LedgerEntry;
+pub use crate::pagination::Page;
+pub use crate::pool::Detail as PoolDetail;
+pub use crate::project::Detail as ProjectDetail;
 pub use crate::retirement::Report as RetirementReport;
-pub use crate::token::{TokenBalanceDetail, TokenEdition};
+pub use crate::serial::Range as SerialRange;
+pub use crate::token::{TokenBalanceDetail, TokenEdition, TokenHolder};
 pub use ink_env::{DefaultEnvironment, Environment};
 pub use ink_lang::codegen::initialize_contract;
 pub use ink_prelude::string::String;
@@ -23,353 +43,3034 @@ pub use scale::{Decode, Encode};
 
 // Type Facades
 pub type CarbonUnit = u64;
+pub type ChallengeId = u64;
+pub type DocumentVersion = u32;
+pub type EventSchemaVersion = u8;
+pub type LedgerSequence = u64;
 pub type MintBeneficiaryAccount = environment::AccountId;
+pub type PoolId = u32;
+pub type ProjectId = u32;
 pub type RegistryId = String;
 pub type RetirementId = u64;
 pub type RetirementReports = GenericVec<RetirementReport>;
+pub type SerialNumber = u64;
+pub type SnapshotId = u32;
 pub type TokenBalances = GenericVec<TokenBalanceDetail>;
 pub type TokenEditions = GenericVec<TokenEdition>;
 pub type TokenId = u32;
+pub type TokenTransferBatch = GenericVec<(environment::AccountId, TokenEditions)>;
 pub type Year = u16;
 
+pub const EVENT_SCHEMA_VERSION: EventSchemaVersion = 1;
+
 #[ink_lang::contract(dynamic_storage_allocator = true)]
 pub mod contract {
     use super::*;
+    use crate::attribute::{CreditKind, CreditOrigin, Filter as AttributeFilter};
+    use crate::buffer::{Reserve as Buffer, Stats as BufferStats};
+    use crate::challenge::{
+        Challenge as TokenChallenge, Docket as Challenges, Resolution as ChallengeResolution,
+        Subject as ChallengeSubject,
+    };
     use crate::custodian::{
-        AddParams as AddCustodianParams, Collections as Custodians, Detail as CustodianDetail,
+        AddParams as AddCustodianParams, Bond as CustodianBond, BondConfig as CustodianBondConfig,
+        Collections as Custodians, Detail as CustodianDetail, Quota as CustodianQuota,
+        RemainingQuota as CustodianRemainingQuota, RequestOutcome as CustodianRequestOutcome,
+        Scope as CustodianScope, SlashTarget as CustodianSlashTarget, Stats as CustodianStats,
+        Status as CustodianStatus, MAX_SLASH_PAYOUTS,
+    };
+    use crate::document::{
+        Archive as Documents, AttachParams as DocumentAttachParams, Document,
+        Subject as DocumentSubject,
+    };
+    use crate::ledger::{EntryKind as LedgerEntryKind, Journal as Ledger};
+    use crate::pool::{CreateParams as PoolCreateParams, Registry as Pools};
+    use crate::project::{
+        Directory as Projects, RegisterParams as ProjectRegisterParams, Status as ProjectStatus,
     };
-    use crate::retirement::{Book as Retirements, Info as RetirementInfo};
+    use crate::receiver::notify as notify_receiver;
+    use crate::retirement::Book as Retirements;
     use crate::token::{
-        Detail as TokenDetail, MintRequestParams as TokenMintParams, Tracker as Tokens,
+        get_supplement_registry_id, AllocationStrategy, Detail as TokenDetail,
+        Issuance as TokenIssuance, MintRequestParams as TokenMintParams, Status as TokenStatus,
+        SupplementRequestParams as TokenSupplementParams, Tracker as Tokens, TransferMemo,
     };
+    use crate::utils::{
+        get_blackhole_address, get_buffer_address, get_pool_address, get_pool_id, hash_registry_id,
+        is_synthetic_address,
+    };
+    use crate::verifier::{
+        AddParams as AddVerifierParams, Attestation as VerifierAttestation,
+        Detail as VerifierDetail, Panel as Verifiers,
+    };
+
+    #[ink(event)]
+    pub struct GovernorChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: AccountId,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct ConfigurationChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        key: ConfigurationKey,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianAdded {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        alias: String,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianRemoved {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        alias: String,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct VerifierAdded {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        alias: String,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct VerifierRemoved {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        alias: String,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianStatusChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        status: CustodianStatus,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianScopeChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        expiry: Option<BlockNumber>,
+        scope: CustodianScope,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianQuotaChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        quota: CustodianQuota,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianBondChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        bond: CustodianBond,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct CustodianBondSlashed {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: AccountId,
+        amount: Balance,
+        payouts: GenericVec<(AccountId, Balance)>,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct ProjectRegistered {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: ProjectId,
+        detail: ProjectDetail,
+    }
+
+    #[ink(event)]
+    pub struct ProjectStatusChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: ProjectId,
+        status: ProjectStatus,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct DocumentAnchored {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        subject: DocumentSubject,
+        document: Document,
+    }
 
     #[ink(event)]
     pub struct TokenMintRequested {
+        version: EventSchemaVersion,
         #[ink(topic)]
         from: AccountId,
         #[ink(topic)]
         to: AccountId,
         #[ink(topic)]
+        registry_id_hash: Hash,
         registry_id: RegistryId,
+        detail: TokenDetail,
+        beneficiary: MintBeneficiaryAccount,
     }
 
     #[ink(event)]
-    pub struct TokenMintApproved {
+    pub struct TokenMintAttested {
+        version: EventSchemaVersion,
         #[ink(topic)]
         from: AccountId,
         #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        attestation: VerifierAttestation,
+    }
+
+    #[ink(event)]
+    pub struct TokenMintApproved {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
         to: AccountId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
         registry_id: RegistryId,
         #[ink(topic)]
         id: TokenId,
+        detail: TokenDetail,
+        beneficiary: MintBeneficiaryAccount,
     }
 
     #[ink(event)]
     pub struct TokenMintDenied {
+        version: EventSchemaVersion,
         #[ink(topic)]
         from: AccountId,
         #[ink(topic)]
         to: AccountId,
         #[ink(topic)]
+        registry_id_hash: Hash,
         registry_id: RegistryId,
+        detail: TokenDetail,
+        beneficiary: MintBeneficiaryAccount,
     }
 
     #[ink(event)]
-    pub struct TokenTransferred {
+    pub struct TokenMintWithdrawn {
+        version: EventSchemaVersion,
         #[ink(topic)]
         from: AccountId,
         #[ink(topic)]
         to: AccountId,
-        editions: TokenEditions,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        detail: TokenDetail,
+        beneficiary: MintBeneficiaryAccount,
     }
 
     #[ink(event)]
-    pub struct TokenRetired {
+    pub struct TokenMintExpired {
+        version: EventSchemaVersion,
         #[ink(topic)]
         from: AccountId,
         #[ink(topic)]
         to: AccountId,
         #[ink(topic)]
-        info: RetirementInfo,
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        detail: TokenDetail,
+        beneficiary: MintBeneficiaryAccount,
     }
 
-    #[ink(storage)]
-    #[derive(SpreadAllocate)]
-    pub struct Wall {
-        block_number: Lazy<BlockNumber>,
-        governor: Lazy<AccountId>,
-        custodians: Lazy<StorageBox<Custodians>>,
-        tokens: Lazy<StorageBox<Tokens>>,
-        retirements: Lazy<StorageBox<Retirements>>,
+    #[ink(event)]
+    pub struct TokenChallengeFiled {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        challenge: TokenChallenge,
     }
 
-    impl Wall {
-        #[ink(constructor)]
-        pub fn instantiate() -> Self {
-            initialize_contract(|contract_context: &mut Self| {
-                Lazy::set(&mut contract_context.governor, Self::env().caller());
-                Lazy::set(
-                    &mut contract_context.block_number,
-                    Self::env().block_number(),
-                );
-                Lazy::set(
-                    &mut contract_context.custodians,
-                    StorageBox::new(Custodians::default()),
-                );
-                Lazy::set(
-                    &mut contract_context.tokens,
-                    StorageBox::new(Tokens::default()),
-                );
-                Lazy::set(
-                    &mut contract_context.retirements,
-                    StorageBox::new(Retirements::default()),
-                );
-            })
-        }
-
-        #[ink(message)]
-        pub fn any_system_debug_get_governor(&self) -> AccountId {
-            *self.governor
-        }
-
-        #[ink(message)]
-        pub fn any_system_debug_get_blocknumber(&self) -> BlockNumber {
-            *self.block_number
-        }
+    #[ink(event)]
+    pub struct TokenChallengeResolved {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        challenge: TokenChallenge,
+    }
 
-        pub fn any_system_debug_get_last_report_id(&self) -> Option<RetirementId> {
-            self.retirements.get_last_report_id()
-        }
+    #[ink(event)]
+    pub struct TokenActivated {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        block_number: BlockNumber,
+    }
 
-        #[ink(message)]
-        pub fn any_system_debug_get_last_minted_id(&self) -> Option<TokenId> {
-            self.tokens.get_last_minted_edition_id()
-        }
+    #[ink(event)]
+    pub struct TokenStatusChanged {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        status: TokenStatus,
+        block_number: BlockNumber,
+    }
 
-        #[ink(message)]
-        pub fn gov_system_terminate(&mut self) -> Result<(), OperationError> {
-            if self.env().caller() != *self.governor {
-                return Err(OperationError::Unauthorized);
-            }
+    #[ink(event)]
+    pub struct TokenInvalidated {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        detail: TokenDetail,
+    }
 
-            self.env().terminate_contract(*self.governor);
-        }
+    #[ink(event)]
+    pub struct TokenHoldersWrittenDown {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        holders: GenericVec<TokenHolder>,
+        remaining_holders: u32,
+    }
 
-        #[ink(message)]
-        pub fn gov_custodian_account_add(
-            &mut self,
-            params: AddCustodianParams,
-        ) -> Result<(), OperationError> {
-            if self.env().caller() != *self.governor {
-                return Err(OperationError::Unauthorized);
-            }
+    #[ink(event)]
+    pub struct BufferWithheld {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        amount: CarbonUnit,
+        block_number: BlockNumber,
+    }
 
-            self.custodians.add(params)
-        }
+    #[ink(event)]
+    pub struct BufferCancelled {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        covered_id: TokenId,
+        amount: CarbonUnit,
+        block_number: BlockNumber,
+    }
 
-        #[ink(message)]
-        pub fn gov_custodian_account_remove(
-            &mut self,
-            id: AccountId,
-        ) -> Result<(), OperationError> {
-            if self.env().caller() != *self.governor {
-                return Err(OperationError::Unauthorized);
-            }
+    #[ink(event)]
+    pub struct TokenSupplementRequested {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+    }
 
-            self.custodians.remove(id)
-        }
+    #[ink(event)]
+    pub struct TokenSupplementApproved {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+        detail: TokenDetail,
+        activation_block: Option<BlockNumber>,
+    }
 
-        #[ink(message)]
-        pub fn any_custodian_account_list(&mut self) -> GenericVec<CustodianDetail> {
-            self.custodians.list()
-        }
+    #[ink(event)]
+    pub struct TokenSupplementActivated {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+        detail: TokenDetail,
+    }
 
-        #[ink(message)]
-        pub fn ctd_token_mint_request(
+    #[ink(event)]
+    pub struct TokenSupplementCancelled {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+    }
+
+    #[ink(event)]
+    pub struct TokenSupplementDenied {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+    }
+
+    #[ink(event)]
+    pub struct TokenSupplementWithdrawn {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+    }
+
+    #[ink(event)]
+    pub struct TokenSupplementExpired {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: TokenId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        registry_id: RegistryId,
+        issuance: TokenIssuance,
+    }
+
+    #[ink(event)]
+    pub struct TokenTransferred {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        editions: TokenEditions,
+        memo: Option<TransferMemo>,
+    }
+
+    #[ink(event)]
+    pub struct TokenRetired {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        registry_id_hash: Hash,
+        report: RetirementReport,
+        detail: TokenDetail,
+    }
+
+    #[ink(event)]
+    pub struct TokenSnapshotCreated {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: SnapshotId,
+        block_number: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct PoolCreated {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: PoolId,
+        detail: PoolDetail,
+    }
+
+    #[ink(event)]
+    pub struct PoolDeposited {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: PoolId,
+        editions: TokenEditions,
+        units: CarbonUnit,
+        detail: PoolDetail,
+    }
+
+    #[ink(event)]
+    pub struct PoolRedeemed {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        id: PoolId,
+        editions: TokenEditions,
+        units: CarbonUnit,
+        fee: CarbonUnit,
+        detail: PoolDetail,
+    }
+
+    #[ink(event)]
+    pub struct PoolRetired {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: PoolId,
+        amount: CarbonUnit,
+        reports: GenericVec<RetirementId>,
+        detail: PoolDetail,
+    }
+
+    #[ink(event)]
+    pub struct PoolWrittenDown {
+        version: EventSchemaVersion,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        id: PoolId,
+        #[ink(topic)]
+        token_id: TokenId,
+        amount: CarbonUnit,
+        detail: PoolDetail,
+    }
+
+    #[ink(storage)]
+    #[derive(SpreadAllocate)]
+    pub struct Wall {
+        block_number: Lazy<BlockNumber>,
+        governor: Lazy<AccountId>,
+        custodians: Lazy<StorageBox<Custodians>>,
+        tokens: Lazy<StorageBox<Tokens>>,
+        retirements: Lazy<StorageBox<Retirements>>,
+        pools: Lazy<StorageBox<Pools>>,
+        ledger: Lazy<StorageBox<Ledger>>,
+        projects: Lazy<StorageBox<Projects>>,
+        documents: Lazy<StorageBox<Documents>>,
+        verifiers: Lazy<StorageBox<Verifiers>>,
+        challenges: Lazy<StorageBox<Challenges>>,
+        buffer: Lazy<StorageBox<Buffer>>,
+    }
+
+    impl Wall {
+        #[ink(constructor)]
+        pub fn instantiate() -> Self {
+            initialize_contract(|contract_context: &mut Self| {
+                Lazy::set(&mut contract_context.governor, Self::env().caller());
+                Self::env().emit_event(GovernorChanged {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: None,
+                    to: Self::env().caller(),
+                    block_number: Self::env().block_number(),
+                });
+                Lazy::set(
+                    &mut contract_context.block_number,
+                    Self::env().block_number(),
+                );
+                Lazy::set(
+                    &mut contract_context.custodians,
+                    StorageBox::new(Custodians::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.tokens,
+                    StorageBox::new(Tokens::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.retirements,
+                    StorageBox::new(Retirements::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.pools,
+                    StorageBox::new(Pools::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.ledger,
+                    StorageBox::new(Ledger::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.projects,
+                    StorageBox::new(Projects::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.documents,
+                    StorageBox::new(Documents::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.verifiers,
+                    StorageBox::new(Verifiers::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.challenges,
+                    StorageBox::new(Challenges::default()),
+                );
+                Lazy::set(
+                    &mut contract_context.buffer,
+                    StorageBox::new(Buffer::default()),
+                );
+            })
+        }
+
+        #[ink(message)]
+        pub fn any_system_debug_get_governor(&self) -> AccountId {
+            *self.governor
+        }
+
+        #[ink(message)]
+        pub fn any_system_debug_get_blocknumber(&self) -> BlockNumber {
+            *self.block_number
+        }
+
+        pub fn any_system_debug_get_last_report_id(&self) -> Option<RetirementId> {
+            self.retirements.get_last_report_id()
+        }
+
+        #[ink(message)]
+        pub fn any_system_debug_get_last_minted_id(&self) -> Option<TokenId> {
+            self.tokens.get_last_minted_edition_id()
+        }
+
+        #[ink(message)]
+        pub fn gov_system_terminate(&mut self) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.env().terminate_contract(*self.governor);
+        }
+
+        #[ink(message)]
+        pub fn gov_system_governor_set(
             &mut self,
-            params: TokenMintParams,
+            governor: AccountId,
         ) -> Result<(), OperationError> {
-            let minter = self.env().caller();
-            let registry_id = params.registry_id.clone();
+            let previous_governor = *self.governor;
 
-            if !self.custodians.contains(minter) {
+            if self.env().caller() != previous_governor {
                 return Err(OperationError::Unauthorized);
             }
 
-            self.tokens.insert_pending_mint(minter, params)?;
-            self.env().emit_event(TokenMintRequested {
-                from: minter,
-                to: *self.governor,
-                registry_id,
+            Lazy::set(&mut self.governor, governor);
+            self.env().emit_event(GovernorChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: Some(previous_governor),
+                to: governor,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_account_add(
+            &mut self,
+            params: AddCustodianParams,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.custodians.add(params)?;
+            self.env().emit_event(CustodianAdded {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                alias: detail.alias,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_account_remove(
+            &mut self,
+            id: AccountId,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.custodians.remove(id)?;
+            self.env().emit_event(CustodianRemoved {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                alias: detail.alias,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_account_status_set(
+            &mut self,
+            id: AccountId,
+            status: CustodianStatus,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.custodians.set_status(id, status)?;
+            self.env().emit_event(CustodianStatusChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                status: detail.status,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_account_scope_set(
+            &mut self,
+            id: AccountId,
+            expiry: Option<BlockNumber>,
+            scope: CustodianScope,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.custodians.set_scope(id, expiry, scope)?;
+            self.env().emit_event(CustodianScopeChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                expiry: detail.expiry,
+                scope: detail.scope,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_quota_set(
+            &mut self,
+            id: AccountId,
+            quota: CustodianQuota,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.custodians.set_quota(id, quota)?;
+            self.env().emit_event(CustodianQuotaChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                quota: detail.quota,
+                block_number: self.env().block_number(),
             });
 
-            Ok(())
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_quota_get_remaining(
+            &mut self,
+            id: AccountId,
+        ) -> Result<CustodianRemainingQuota, OperationError> {
+            self.custodians.get_remaining_quota(id)
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_stats_get(
+            &mut self,
+            id: AccountId,
+        ) -> Result<CustodianStats, OperationError> {
+            self.custodians.get_stats(id)
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_bond_config_set(
+            &mut self,
+            config: CustodianBondConfig,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.custodians.set_bond_config(config);
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                key: ConfigurationKey::CustodianBond,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_bond_config_get(&mut self) -> CustodianBondConfig {
+            self.custodians.get_bond_config()
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_bond_get(
+            &mut self,
+            id: AccountId,
+        ) -> Result<CustodianBond, OperationError> {
+            self.custodians.get_bond(id)
+        }
+
+        #[ink(message, payable)]
+        pub fn ctd_custodian_bond_deposit(&mut self) -> Result<(), OperationError> {
+            let custodian = self.env().caller();
+            let amount = self.env().transferred_value();
+            let bond = self.custodians.deposit_bond(custodian, amount)?;
+            self.env().emit_event(CustodianBondChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: custodian,
+                id: custodian,
+                bond,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn ctd_custodian_bond_unbond(&mut self, amount: Balance) -> Result<(), OperationError> {
+            let custodian = self.env().caller();
+            let bond = self.custodians.unbond(custodian, amount)?;
+            self.env().emit_event(CustodianBondChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: custodian,
+                id: custodian,
+                bond,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn ctd_custodian_bond_withdraw(&mut self) -> Result<Balance, OperationError> {
+            let custodian = self.env().caller();
+            let withdrawable = self.custodians.get_withdrawable_bond(custodian)?;
+            self.env()
+                .transfer(custodian, withdrawable)
+                .map_err(|_| OperationError::BondTransferFailed)?;
+            self.custodians.release_unbonded(custodian)?;
+            self.env().emit_event(CustodianBondChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: custodian,
+                id: custodian,
+                bond: self.custodians.get_bond(custodian)?,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(withdrawable)
+        }
+
+        #[ink(message)]
+        pub fn gov_custodian_bond_slash(
+            &mut self,
+            id: AccountId,
+            amount: Balance,
+            target: CustodianSlashTarget,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let bond = self.custodians.get_bond(id)?;
+
+            if bond.bonded.saturating_add(bond.unbonding) < amount {
+                return Err(OperationError::CustodianBondInsufficient);
+            }
+
+            let treasury = self.custodians.get_treasury();
+            let mut payouts = GenericVec::new();
+
+            match target {
+                CustodianSlashTarget::Treasury => payouts.push((treasury?, amount)),
+                CustodianSlashTarget::Holders(token_id) => {
+                    let token_detail = self.tokens.get_edition_details(token_id)?;
+
+                    if token_detail.minter != id {
+                        return Err(OperationError::CustodianSlashTargetInvalid);
+                    }
+
+                    match token_detail.invalidation {
+                        None => return Err(OperationError::TokenNotInvalidated),
+                        Some(invalidation) if invalidation.remaining_holders > 0 => {
+                            return Err(OperationError::TokenInvalidationPending)
+                        }
+                        Some(_) => (),
+                    }
+
+                    let mut holders = self
+                        .tokens
+                        .get_cancelled_holdings(token_id, MAX_SLASH_PAYOUTS)?;
+                    holders.retain(|holder| !is_synthetic_address(&holder.account_id));
+                    let total_held = holders.iter().fold(0 as Balance, |total, holder| {
+                        total.saturating_add(holder.balance as Balance)
+                    });
+
+                    if total_held == 0 {
+                        payouts.push((treasury?, amount));
+                    } else {
+                        let mut remaining_amount = amount;
+
+                        for holder in holders {
+                            let balance = holder.balance as Balance;
+                            let payout = amount
+                                .checked_mul(balance)
+                                .map(|share| share / total_held)
+                                .unwrap_or_else(|| amount / total_held * balance)
+                                .min(remaining_amount);
+
+                            if payout > 0 {
+                                payouts.push((holder.account_id, payout));
+                                remaining_amount = remaining_amount.saturating_sub(payout);
+                            }
+                        }
+
+                        if remaining_amount > 0 {
+                            payouts.push((treasury?, remaining_amount));
+                        }
+                    }
+                }
+            }
+
+            for (account_id, payout) in &payouts {
+                self.env()
+                    .transfer(*account_id, *payout)
+                    .map_err(|_| OperationError::BondTransferFailed)?;
+            }
+
+            self.custodians.slash_bond(id, amount)?;
+            self.env().emit_event(CustodianBondSlashed {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id,
+                amount,
+                payouts,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_account_get_by_id(
+            &mut self,
+            id: AccountId,
+        ) -> Result<CustodianDetail, OperationError> {
+            self.custodians.get_by_id(id)
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_account_list(&mut self) -> GenericVec<CustodianDetail> {
+            self.custodians.list()
+        }
+
+        #[ink(message)]
+        pub fn any_custodian_account_page(
+            &mut self,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, CustodianDetail> {
+            self.custodians.list_page(start, limit)
+        }
+
+        #[ink(message)]
+        pub fn gov_verifier_account_add(
+            &mut self,
+            params: AddVerifierParams,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.verifiers.add(params)?;
+            self.env().emit_event(VerifierAdded {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                alias: detail.alias,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_verifier_account_remove(&mut self, id: AccountId) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let detail = self.verifiers.remove(id)?;
+            self.env().emit_event(VerifierRemoved {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                alias: detail.alias,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_verifier_threshold_set(&mut self, threshold: u32) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.verifiers.set_threshold(threshold);
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                key: ConfigurationKey::VerifierThreshold,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_verifier_threshold_get(&mut self) -> u32 {
+            self.verifiers.get_threshold()
+        }
+
+        #[ink(message)]
+        pub fn any_verifier_account_get_by_id(
+            &mut self,
+            id: AccountId,
+        ) -> Result<VerifierDetail, OperationError> {
+            self.verifiers.get_by_id(id)
+        }
+
+        #[ink(message)]
+        pub fn any_verifier_account_page(
+            &mut self,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, VerifierDetail> {
+            self.verifiers.list_page(start, limit)
+        }
+
+        #[ink(message)]
+        pub fn ctd_project_register(
+            &mut self,
+            params: ProjectRegisterParams,
+            documents: GenericVec<DocumentAttachParams>,
+        ) -> Result<ProjectId, OperationError> {
+            let proposer = self.env().caller();
+            self.custodians.check_active(proposer)?;
+            self.documents.check_params(&documents)?;
+            let detail = self.projects.register(proposer, params);
+            let project_id = detail.id;
+            self.env().emit_event(ProjectRegistered {
+                version: EVENT_SCHEMA_VERSION,
+                from: proposer,
+                id: project_id,
+                detail,
+            });
+            self.anchor_documents(DocumentSubject::Project(project_id), proposer, documents)?;
+
+            Ok(project_id)
+        }
+
+        #[ink(message)]
+        pub fn ctd_project_document_append(
+            &mut self,
+            project_id: ProjectId,
+            documents: GenericVec<DocumentAttachParams>,
+        ) -> Result<(), OperationError> {
+            let submitter = self.env().caller();
+            self.custodians.check_active(submitter)?;
+
+            if self.projects.get_by_id(project_id)?.proposer != submitter {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.anchor_documents(DocumentSubject::Project(project_id), submitter, documents)
+        }
+
+        #[ink(message)]
+        pub fn any_project_document_page(
+            &mut self,
+            project_id: ProjectId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, Document> {
+            self.documents
+                .get_page(DocumentSubject::Project(project_id), start, limit)
+        }
+
+        #[ink(message)]
+        pub fn gov_project_approve(&mut self, project_id: ProjectId) -> Result<(), OperationError> {
+            self.review_project(project_id, ProjectStatus::Approved)
+        }
+
+        #[ink(message)]
+        pub fn gov_project_reject(&mut self, project_id: ProjectId) -> Result<(), OperationError> {
+            self.review_project(project_id, ProjectStatus::Rejected)
+        }
+
+        #[ink(message)]
+        pub fn any_project_info_get_by_id(
+            &mut self,
+            project_id: ProjectId,
+        ) -> Result<ProjectDetail, OperationError> {
+            self.projects.get_by_id(project_id)
+        }
+
+        #[ink(message)]
+        pub fn any_project_page(
+            &mut self,
+            start: Option<ProjectId>,
+            limit: u32,
+        ) -> Page<ProjectId, ProjectDetail> {
+            self.projects.list_page(start, limit)
+        }
+
+        #[ink(message)]
+        pub fn any_project_edition_page(
+            &mut self,
+            project_id: ProjectId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Result<Page<u32, TokenDetail>, OperationError> {
+            let page = self
+                .projects
+                .get_edition_id_page(project_id, start, limit)?;
+            let mut editions = GenericVec::new();
+
+            for token_id in page.items {
+                editions.push(self.tokens.get_edition_details(token_id)?);
+            }
+
+            Ok(Page {
+                items: editions,
+                next_cursor: page.next_cursor,
+            })
+        }
+
+        #[ink(message)]
+        pub fn any_project_supply_get(
+            &mut self,
+            project_id: ProjectId,
+        ) -> Result<CarbonUnit, OperationError> {
+            let mut project_supply = 0;
+
+            for token_id in self.projects.get_edition_ids(project_id)? {
+                project_supply += self.tokens.get_supply_by_id(token_id)?;
+            }
+
+            Ok(project_supply)
+        }
+
+        #[ink(message)]
+        pub fn any_project_retired_supply_get(
+            &mut self,
+            project_id: ProjectId,
+        ) -> Result<CarbonUnit, OperationError> {
+            let mut project_retired_supply = 0;
+
+            for token_id in self.projects.get_edition_ids(project_id)? {
+                project_retired_supply += self.tokens.get_retired_by_id(token_id)?;
+            }
+
+            Ok(project_retired_supply)
+        }
+
+        #[ink(message)]
+        pub fn ctd_token_mint_request(
+            &mut self,
+            params: TokenMintParams,
+            documents: GenericVec<DocumentAttachParams>,
+        ) -> Result<(), OperationError> {
+            let minter = self.env().caller();
+            let registry_id = params.registry_id.clone();
+            let beneficiary = params.beneficiary;
+
+            let amount = params.verified_carbon_unit;
+            self.projects.check_is_approved(params.project_id)?;
+            let registry = self.projects.get_by_id(params.project_id)?.registry;
+            self.custodians
+                .check_can_mint(minter, &registry_id, params.issuance_year, amount)?;
+            self.documents.check_params(&documents)?;
+
+            let detail = self.tokens.insert_pending_mint(minter, registry, params)?;
+            self.custodians.record_request(minter, amount);
+            self.anchor_documents(DocumentSubject::Edition(detail.id), minter, documents)?;
+            self.env().emit_event(TokenMintRequested {
+                version: EVENT_SCHEMA_VERSION,
+                from: minter,
+                to: *self.governor,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                detail,
+                beneficiary,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn ctd_token_mint_document_set(
+            &mut self,
+            registry_id: RegistryId,
+            documents: GenericVec<DocumentAttachParams>,
+        ) -> Result<(), OperationError> {
+            let minter = self.env().caller();
+            self.custodians.check_active(minter)?;
+            let (pending_detail, _) = self.tokens.get_pending_mint(&registry_id)?;
+
+            if pending_detail.minter != minter {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.documents.check_params(&documents)?;
+            let subject = DocumentSubject::Edition(pending_detail.id);
+            self.documents.clear(subject);
+            self.verifiers.clear_attestations(&registry_id);
+
+            self.anchor_documents(subject, minter, documents)
+        }
+
+        #[ink(message)]
+        pub fn ctd_token_document_append(
+            &mut self,
+            token_id: TokenId,
+            documents: GenericVec<DocumentAttachParams>,
+        ) -> Result<(), OperationError> {
+            let submitter = self.env().caller();
+            self.custodians.check_active(submitter)?;
+
+            if self.tokens.get_edition_details(token_id)?.minter != submitter {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.anchor_documents(DocumentSubject::Edition(token_id), submitter, documents)
+        }
+
+        #[ink(message)]
+        pub fn any_token_document_page(
+            &mut self,
+            token_id: TokenId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, Document> {
+            self.documents
+                .get_page(DocumentSubject::Edition(token_id), start, limit)
+        }
+
+        #[ink(message)]
+        pub fn vvb_token_mint_attest(
+            &mut self,
+            registry_id: RegistryId,
+            report_hash: Option<Hash>,
+        ) -> Result<(), OperationError> {
+            let verifier = self.env().caller();
+
+            let (minter, token_id) = match self.tokens.get_pending_mint(&registry_id) {
+                Ok((pending_detail, _)) => {
+                    if self.tokens.is_pending_mint_expired(&pending_detail) {
+                        return Err(OperationError::TokenMintRequestExpired);
+                    }
+
+                    (pending_detail.minter, pending_detail.id)
+                }
+                Err(_) => {
+                    let pending_issuance = self.tokens.get_pending_supplement(&registry_id)?;
+
+                    if self.tokens.is_pending_supplement_expired(&pending_issuance) {
+                        return Err(OperationError::TokenMintRequestExpired);
+                    }
+
+                    (pending_issuance.minter, pending_issuance.token_id)
+                }
+            };
+
+            let document_version = self
+                .documents
+                .get_version(DocumentSubject::Edition(token_id));
+            let attestation = self.verifiers.attest(
+                verifier,
+                minter,
+                &registry_id,
+                report_hash,
+                document_version,
+            )?;
+            self.env().emit_event(TokenMintAttested {
+                version: EVENT_SCHEMA_VERSION,
+                from: verifier,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                attestation,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_mint_attestation_get(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> GenericVec<VerifierAttestation> {
+            self.verifiers.get_attestations(&registry_id)
+        }
+
+        #[ink(message)]
+        pub fn gov_token_mint_approve(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let (pending_detail, _) = self.tokens.get_pending_mint(&registry_id)?;
+
+            if self.tokens.is_pending_mint_expired(&pending_detail) {
+                return Err(OperationError::TokenMintRequestExpired);
+            }
+
+            self.custodians.check_can_approve(pending_detail.minter)?;
+            self.verifiers.check_threshold(
+                &registry_id,
+                self.documents
+                    .get_version(DocumentSubject::Edition(pending_detail.id)),
+            )?;
+            let buffer_amount = self
+                .buffer
+                .get_buffer_amount(&pending_detail, pending_detail.supply);
+            let (detail, target_account_id) = self
+                .tokens
+                .approve_pending_mint(&registry_id, buffer_amount)?;
+            self.verifiers.clear_attestations(&registry_id);
+            self.projects.link_edition(detail.project_id, detail.id);
+
+            if let Some(challenge_period) = self.challenges.get_challenge_period() {
+                let activation_block = self.env().block_number().saturating_add(challenge_period);
+                self.tokens.schedule_activation(detail.id, activation_block);
+            }
+
+            self.custodians.close_request(
+                detail.minter,
+                CustodianRequestOutcome::Approved(detail.supply),
+            );
+            let token_id = detail.id;
+            let mut editions = GenericVec::new();
+            editions.push(TokenEdition {
+                id: token_id,
+                amount: detail.supply - buffer_amount,
+            });
+            self.env().emit_event(TokenMintApproved {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                to: detail.minter,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                id: detail.id,
+                detail,
+                beneficiary: target_account_id,
+            });
+            self.ledger.record(
+                LedgerEntryKind::Mint,
+                *self.governor,
+                target_account_id,
+                &editions,
+            );
+            self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                to: target_account_id,
+                editions,
+                memo: None,
+            });
+
+            if buffer_amount > 0 {
+                self.buffer.record_withheld(token_id, buffer_amount);
+                let mut buffer_editions = GenericVec::new();
+                buffer_editions.push(TokenEdition {
+                    id: token_id,
+                    amount: buffer_amount,
+                });
+                self.ledger.record(
+                    LedgerEntryKind::Mint,
+                    *self.governor,
+                    get_buffer_address(),
+                    &buffer_editions,
+                );
+                self.env().emit_event(TokenTransferred {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: *self.governor,
+                    to: get_buffer_address(),
+                    editions: buffer_editions,
+                    memo: None,
+                });
+                self.env().emit_event(BufferWithheld {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: *self.governor,
+                    id: token_id,
+                    amount: buffer_amount,
+                    block_number: self.env().block_number(),
+                });
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_token_mint_deny(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let (detail, beneficiary) = self.tokens.deny_pending_mint(&registry_id)?;
+            self.documents.clear(DocumentSubject::Edition(detail.id));
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians
+                .close_request(detail.minter, CustodianRequestOutcome::Denied);
+            self.env().emit_event(TokenMintDenied {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                to: detail.minter,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                detail,
+                beneficiary,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn ctd_token_mint_withdraw(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            let minter = self.env().caller();
+            let (detail, beneficiary) = self.tokens.withdraw_pending_mint(minter, &registry_id)?;
+            self.documents.clear(DocumentSubject::Edition(detail.id));
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians
+                .close_request(minter, CustodianRequestOutcome::Withdrawn);
+            self.env().emit_event(TokenMintWithdrawn {
+                version: EVENT_SCHEMA_VERSION,
+                from: minter,
+                to: *self.governor,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                detail,
+                beneficiary,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_mint_expire(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            let (detail, beneficiary) = self.tokens.expire_pending_mint(&registry_id)?;
+            self.documents.clear(DocumentSubject::Edition(detail.id));
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians
+                .close_request(detail.minter, CustodianRequestOutcome::Expired);
+            self.env().emit_event(TokenMintExpired {
+                version: EVENT_SCHEMA_VERSION,
+                from: self.env().caller(),
+                to: detail.minter,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                detail,
+                beneficiary,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_token_mint_ttl_set(
+            &mut self,
+            pending_mint_ttl: Option<BlockNumber>,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.tokens.set_pending_mint_ttl(pending_mint_ttl);
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                key: ConfigurationKey::MintRequestTtl,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_mint_ttl_get(&mut self) -> Option<BlockNumber> {
+            self.tokens.get_pending_mint_ttl()
+        }
+
+        #[ink(message)]
+        pub fn ctd_token_supplement_request(
+            &mut self,
+            params: TokenSupplementParams,
+        ) -> Result<(), OperationError> {
+            let minter = self.env().caller();
+            let detail = self.tokens.get_edition_details(params.token_id)?;
+            let registry_id =
+                get_supplement_registry_id(&detail.registry_id, &params.serial_suffix);
+            let amount = params.verified_carbon_unit;
+            self.projects.check_is_approved(detail.project_id)?;
+            self.custodians
+                .check_can_mint(minter, &registry_id, detail.year, amount)?;
+
+            let (registry_id, issuance) = self.tokens.insert_pending_supplement(minter, params)?;
+            self.custodians.record_request(minter, amount);
+            self.env().emit_event(TokenSupplementRequested {
+                version: EVENT_SCHEMA_VERSION,
+                from: minter,
+                id: issuance.token_id,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                issuance,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_token_supplement_approve(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let pending_issuance = self.tokens.get_pending_supplement(&registry_id)?;
+            let pending_detail = self.tokens.get_edition_details(pending_issuance.token_id)?;
+            self.custodians.check_can_approve(pending_issuance.minter)?;
+            self.verifiers.check_threshold(
+                &registry_id,
+                self.documents
+                    .get_version(DocumentSubject::Edition(pending_issuance.token_id)),
+            )?;
+            let buffer_amount = self
+                .buffer
+                .get_buffer_amount(&pending_detail, pending_issuance.amount);
+            let activation_block = self
+                .challenges
+                .get_challenge_period()
+                .map(|challenge_period| self.env().block_number().saturating_add(challenge_period));
+            let (detail, issuance) = self.tokens.approve_pending_supplement(
+                &registry_id,
+                buffer_amount,
+                activation_block,
+            )?;
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians.close_request(
+                issuance.minter,
+                CustodianRequestOutcome::Approved(issuance.amount),
+            );
+            self.env().emit_event(TokenSupplementApproved {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: issuance.token_id,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                issuance: issuance.clone(),
+                detail,
+                activation_block,
+            });
+
+            if activation_block.is_none() {
+                self.record_supplement_issuance(&issuance, buffer_amount);
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_token_supplement_deny(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let issuance = self.tokens.deny_pending_supplement(&registry_id)?;
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians
+                .close_request(issuance.minter, CustodianRequestOutcome::Denied);
+            self.env().emit_event(TokenSupplementDenied {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: issuance.token_id,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                issuance,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn ctd_token_supplement_withdraw(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            let minter = self.env().caller();
+            let issuance = self
+                .tokens
+                .withdraw_pending_supplement(minter, &registry_id)?;
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians
+                .close_request(minter, CustodianRequestOutcome::Withdrawn);
+            self.env().emit_event(TokenSupplementWithdrawn {
+                version: EVENT_SCHEMA_VERSION,
+                from: minter,
+                id: issuance.token_id,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                issuance,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_supplement_expire(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            let issuance = self.tokens.expire_pending_supplement(&registry_id)?;
+            self.verifiers.clear_attestations(&registry_id);
+            self.custodians
+                .close_request(issuance.minter, CustodianRequestOutcome::Expired);
+            self.env().emit_event(TokenSupplementExpired {
+                version: EVENT_SCHEMA_VERSION,
+                from: self.env().caller(),
+                id: issuance.token_id,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                issuance,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_supplement_get(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<TokenIssuance, OperationError> {
+            self.tokens.get_pending_supplement(&registry_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_issuance_page_by_id(
+            &mut self,
+            token_id: TokenId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Result<Page<u32, TokenIssuance>, OperationError> {
+            self.tokens.get_issuance_page(token_id, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn gov_token_challenge_period_set(
+            &mut self,
+            challenge_period: Option<BlockNumber>,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.challenges.set_challenge_period(challenge_period);
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                key: ConfigurationKey::ChallengePeriod,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_challenge_period_get(&mut self) -> Option<BlockNumber> {
+            self.challenges.get_challenge_period()
+        }
+
+        #[ink(message)]
+        pub fn any_token_challenge_file(
+            &mut self,
+            token_id: TokenId,
+            evidence_hash: Hash,
+        ) -> Result<ChallengeId, OperationError> {
+            self.tokens.check_is_pending_activation(token_id)?;
+            self.file_challenge(token_id, ChallengeSubject::Edition(token_id), evidence_hash)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supplement_challenge_file(
+            &mut self,
+            registry_id: RegistryId,
+            evidence_hash: Hash,
+        ) -> Result<ChallengeId, OperationError> {
+            let token_id = self
+                .tokens
+                .get_locked_supplement(&registry_id)?
+                .issuance
+                .token_id;
+            self.tokens
+                .check_is_supplement_pending_activation(&registry_id)?;
+            self.file_challenge(
+                token_id,
+                ChallengeSubject::Supplement(registry_id),
+                evidence_hash,
+            )
+        }
+
+        fn file_challenge(
+            &mut self,
+            token_id: TokenId,
+            subject: ChallengeSubject,
+            evidence_hash: Hash,
+        ) -> Result<ChallengeId, OperationError> {
+            let challenger = self.env().caller();
+
+            if !self.verifiers.contains(challenger)
+                && self
+                    .tokens
+                    .get_account_balance_by_id(challenger, token_id)?
+                    == 0
+            {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let challenge = self
+                .challenges
+                .file(token_id, subject, challenger, evidence_hash)?;
+            let challenge_id = challenge.id;
+            self.env().emit_event(TokenChallengeFiled {
+                version: EVENT_SCHEMA_VERSION,
+                from: challenger,
+                id: token_id,
+                challenge,
+            });
+
+            Ok(challenge_id)
+        }
+
+        #[ink(message)]
+        pub fn gov_token_challenge_resolve(
+            &mut self,
+            challenge_id: ChallengeId,
+            resolution: ChallengeResolution,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let challenge = self.challenges.resolve(challenge_id, resolution)?;
+            let token_id = challenge.token_id;
+            let subject = challenge.subject.clone();
+            let evidence_hash = challenge.evidence_hash;
+            self.env().emit_event(TokenChallengeResolved {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: token_id,
+                challenge,
+            });
+
+            if resolution != ChallengeResolution::Upheld {
+                return Ok(());
+            }
+
+            match subject {
+                ChallengeSubject::Edition(_) => {
+                    if self.tokens.get_edition_details(token_id)?.status != TokenStatus::Invalidated
+                    {
+                        self.invalidate_edition(
+                            token_id,
+                            String::from("challenge upheld"),
+                            evidence_hash,
+                        )?;
+                    }
+                }
+                ChallengeSubject::Supplement(registry_id) => {
+                    let locked_supplement = self.tokens.cancel_locked_supplement(&registry_id)?;
+                    let issuance = locked_supplement.issuance;
+                    self.custodians
+                        .record_cancellation(issuance.minter, issuance.amount);
+                    self.env().emit_event(TokenSupplementCancelled {
+                        version: EVENT_SCHEMA_VERSION,
+                        from: *self.governor,
+                        id: token_id,
+                        registry_id_hash: hash_registry_id(&registry_id),
+                        registry_id,
+                        issuance,
+                    });
+                }
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_activate(&mut self, token_id: TokenId) -> Result<(), OperationError> {
+            self.challenges
+                .check_can_activate(&ChallengeSubject::Edition(token_id))?;
+            self.tokens.activate(token_id)?;
+            self.env().emit_event(TokenActivated {
+                version: EVENT_SCHEMA_VERSION,
+                from: self.env().caller(),
+                id: token_id,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_activation_get_by_id(&mut self, token_id: TokenId) -> Option<BlockNumber> {
+            self.tokens.get_activation_block(token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supplement_activate(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Result<(), OperationError> {
+            self.challenges
+                .check_can_activate(&ChallengeSubject::Supplement(registry_id.clone()))?;
+            let (detail, locked_supplement) = self.tokens.activate_supplement(&registry_id)?;
+            let issuance = locked_supplement.issuance;
+            self.env().emit_event(TokenSupplementActivated {
+                version: EVENT_SCHEMA_VERSION,
+                from: self.env().caller(),
+                id: issuance.token_id,
+                registry_id_hash: hash_registry_id(&registry_id),
+                registry_id,
+                issuance: issuance.clone(),
+                detail,
+            });
+            self.record_supplement_issuance(&issuance, locked_supplement.buffer_amount);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_token_supplement_activation_get_by_id(
+            &mut self,
+            registry_id: RegistryId,
+        ) -> Option<BlockNumber> {
+            self.tokens
+                .get_locked_supplement(&registry_id)
+                .ok()
+                .map(|locked_supplement| locked_supplement.activation_block)
+        }
+
+        #[ink(message)]
+        pub fn any_token_challenge_get_by_id(
+            &mut self,
+            challenge_id: ChallengeId,
+        ) -> Result<TokenChallenge, OperationError> {
+            self.challenges.get_by_id(challenge_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_challenge_page_by_id(
+            &mut self,
+            token_id: TokenId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, TokenChallenge> {
+            self.challenges
+                .get_edition_challenge_page(token_id, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn gov_token_edition_status_set(
+            &mut self,
+            token_id: TokenId,
+            status: TokenStatus,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.tokens.set_status(token_id, status)?;
+            self.env().emit_event(TokenStatusChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: token_id,
+                status,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_token_edition_invalidate(
+            &mut self,
+            token_id: TokenId,
+            reason: String,
+            evidence_hash: Hash,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.invalidate_edition(token_id, reason, evidence_hash)
+        }
+
+        #[ink(message)]
+        pub fn any_token_invalidation_continue(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<(), OperationError> {
+            self.write_down_invalidated_holders(token_id)
+        }
+
+        #[ink(message)]
+        pub fn gov_buffer_rate_set_by_project(
+            &mut self,
+            project_id: ProjectId,
+            rate_bps: Option<u16>,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.projects.get_by_id(project_id)?;
+            self.buffer.set_project_rate(project_id, rate_bps)?;
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                key: ConfigurationKey::BufferRate,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_buffer_rate_set_by_category(
+            &mut self,
+            kind: CreditKind,
+            origin: CreditOrigin,
+            rate_bps: Option<u16>,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            self.buffer.set_category_rate(kind, origin, rate_bps)?;
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                key: ConfigurationKey::BufferRate,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn gov_buffer_credit_cancel(
+            &mut self,
+            token_id: TokenId,
+            amount: CarbonUnit,
+            covered_token_id: TokenId,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let covered_detail = self.tokens.get_edition_details(covered_token_id)?;
+            let cancelled = match (covered_detail.status, &covered_detail.invalidation) {
+                (TokenStatus::Invalidated, Some(invalidation)) => invalidation.cancelled,
+                _ => return Err(OperationError::BufferCoverageNotRequired),
+            };
+
+            self.buffer
+                .check_can_cover(covered_token_id, amount, cancelled)?;
+            self.tokens.cancel_buffer_credit(token_id, amount)?;
+            self.buffer.record_cancelled(covered_token_id, amount);
+            self.custodians
+                .record_cancellation(covered_detail.minter, amount);
+            let mut editions = GenericVec::new();
+            editions.push(TokenEdition {
+                id: token_id,
+                amount,
+            });
+            self.ledger.record(
+                LedgerEntryKind::Cancellation,
+                get_buffer_address(),
+                get_blackhole_address(),
+                &editions,
+            );
+            self.env().emit_event(BufferCancelled {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: token_id,
+                covered_id: covered_token_id,
+                amount,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn any_buffer_rate_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<u16, OperationError> {
+            let detail = self.tokens.get_edition_details(token_id)?;
+
+            Ok(self.buffer.get_rate(&detail))
+        }
+
+        #[ink(message)]
+        pub fn any_buffer_withheld_get_by_id(&mut self, token_id: TokenId) -> CarbonUnit {
+            self.buffer.get_withheld_by_id(token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_buffer_covered_get_by_id(&mut self, token_id: TokenId) -> CarbonUnit {
+            self.buffer.get_covered_by_id(token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_buffer_balance_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens
+                .get_account_balance_by_id(get_buffer_address(), token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_buffer_stats_get(&mut self) -> BufferStats {
+            self.buffer.get_stats()
+        }
+
+        #[ink(message)]
+        pub fn any_token_mint_info_get_last(&mut self) -> Result<TokenDetail, OperationError> {
+            self.tokens.get_last_minted_edition_info()
+        }
+
+        #[ink(message)]
+        pub fn any_token_mint_info_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<TokenDetail, OperationError> {
+            self.tokens.get_minted_edition_by_id(token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_edition_page(
+            &mut self,
+            start: Option<TokenId>,
+            limit: u32,
+        ) -> Page<TokenId, TokenDetail> {
+            self.tokens.get_minted_edition_page(start, limit)
+        }
+
+        #[ink(message)]
+        pub fn any_token_edition_page_by_year(
+            &mut self,
+            year: Year,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Result<Page<u32, TokenDetail>, OperationError> {
+            self.tokens
+                .get_minted_edition_page_by_year(year, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn any_token_edition_page_by_attributes(
+            &mut self,
+            filter: AttributeFilter,
+            start: Option<TokenId>,
+            limit: u32,
+        ) -> Page<TokenId, TokenDetail> {
+            self.tokens
+                .get_minted_edition_page_by_attributes(&filter, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn any_token_holders_get_by_id(
+            &mut self,
+            token_id: TokenId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Result<Page<u32, TokenHolder>, OperationError> {
+            self.tokens.get_holder_page(token_id, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn any_token_holders_count_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<u32, OperationError> {
+            self.tokens.get_holder_count(token_id)
+        }
+
+        #[ink(message)]
+        pub fn gov_token_snapshot_create(
+            &mut self,
+            name: String,
+        ) -> Result<SnapshotId, OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let snapshot_id = self.tokens.create_snapshot(name);
+            self.env().emit_event(TokenSnapshotCreated {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: snapshot_id,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(snapshot_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_snapshot_get_by_id(
+            &mut self,
+            snapshot_id: SnapshotId,
+        ) -> Result<TokenSnapshot, OperationError> {
+            self.tokens.get_snapshot_by_id(snapshot_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supply_get_at_block(
+            &mut self,
+            token_id: TokenId,
+            block: BlockNumber,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_supply_at(token_id, block)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supply_get_at_snapshot(
+            &mut self,
+            token_id: TokenId,
+            snapshot_id: SnapshotId,
+        ) -> Result<CarbonUnit, OperationError> {
+            let snapshot = self.tokens.get_snapshot_by_id(snapshot_id)?;
+
+            self.tokens.get_supply_at(token_id, snapshot.block_number)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supply_get_total(&mut self) -> CarbonUnit {
+            self.tokens.get_total_supply()
+        }
+
+        #[ink(message)]
+        pub fn any_token_supply_get_by_year(
+            &mut self,
+            year: Year,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_supply_by_year(year)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supply_get_by_attributes(
+            &mut self,
+            filter: AttributeFilter,
+        ) -> CarbonUnit {
+            self.tokens.get_supply_by_attributes(&filter)
+        }
+
+        #[ink(message)]
+        pub fn any_token_supply_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_supply_by_id(token_id)
+        }
+        #[ink(message)]
+        pub fn any_token_retired_supply_get_total(&mut self) -> CarbonUnit {
+            self.tokens.get_total_retired()
+        }
+
+        #[ink(message)]
+        pub fn any_token_retired_supply_get_by_year(
+            &mut self,
+            year: Year,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_retired_by_year(year)
+        }
+
+        #[ink(message)]
+        pub fn any_token_retired_supply_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_retired_by_id(token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_token_retired_supply_get_by_attributes(
+            &mut self,
+            filter: AttributeFilter,
+        ) -> CarbonUnit {
+            self.tokens.get_retired_by_attributes(&filter)
+        }
+
+        #[ink(message)]
+        pub fn own_token_serial_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<GenericVec<SerialRange>, OperationError> {
+            let account_context = self.env().caller();
+
+            self.tokens
+                .get_account_serials_by_id(account_context, token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_serial_get_by_id(
+            &mut self,
+            account_id: AccountId,
+            token_id: TokenId,
+        ) -> Result<GenericVec<SerialRange>, OperationError> {
+            self.tokens.get_account_serials_by_id(account_id, token_id)
+        }
+
+        #[ink(message)]
+        pub fn own_token_balance_get_all(&mut self) -> TokenBalances {
+            let account_context = self.env().caller();
+
+            self.tokens.get_account_balances(account_context)
+        }
+
+        #[ink(message)]
+        pub fn own_token_balance_page(
+            &mut self,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, TokenBalanceDetail> {
+            let account_context = self.env().caller();
+
+            self.tokens
+                .get_account_balance_page(account_context, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn own_token_balance_get_by_id(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<CarbonUnit, OperationError> {
+            let account_context = self.env().caller();
+
+            self.tokens
+                .get_account_balance_by_id(account_context, token_id)
+        }
+
+        #[ink(message)]
+        pub fn own_token_balance_get_by_year(
+            &mut self,
+            year: Year,
+        ) -> Result<CarbonUnit, OperationError> {
+            let account_context = self.env().caller();
+
+            self.tokens
+                .get_account_balance_by_year(account_context, year)
+        }
+
+        #[ink(message)]
+        pub fn own_token_balance_get_total(&mut self) -> CarbonUnit {
+            let account_context = self.env().caller();
+
+            self.tokens.get_account_total_balance(account_context)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_all(
+            &mut self,
+            target_account_id: AccountId,
+        ) -> Result<(), OperationError> {
+            self.transfer_all(target_account_id, GenericVec::new(), None)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_all_with_memo(
+            &mut self,
+            target_account_id: AccountId,
+            memo: TransferMemo,
+        ) -> Result<(), OperationError> {
+            self.transfer_all(target_account_id, GenericVec::new(), Some(memo))
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_all_with_data(
+            &mut self,
+            target_account_id: AccountId,
+            memo: Option<TransferMemo>,
+            data: GenericVec<u8>,
+        ) -> Result<(), OperationError> {
+            self.transfer_all(target_account_id, data, memo)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_by_id(
+            &mut self,
+            target_account_id: AccountId,
+            token_id: TokenId,
+            token_amount: CarbonUnit,
+        ) -> Result<(), OperationError> {
+            self.transfer_by_id(
+                target_account_id,
+                token_id,
+                token_amount,
+                GenericVec::new(),
+                None,
+            )
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_by_id_with_memo(
+            &mut self,
+            target_account_id: AccountId,
+            token_id: TokenId,
+            token_amount: CarbonUnit,
+            memo: TransferMemo,
+        ) -> Result<(), OperationError> {
+            self.transfer_by_id(
+                target_account_id,
+                token_id,
+                token_amount,
+                GenericVec::new(),
+                Some(memo),
+            )
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_by_id_with_data(
+            &mut self,
+            target_account_id: AccountId,
+            token_id: TokenId,
+            token_amount: CarbonUnit,
+            memo: Option<TransferMemo>,
+            data: GenericVec<u8>,
+        ) -> Result<(), OperationError> {
+            self.transfer_by_id(target_account_id, token_id, token_amount, data, memo)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_by_year(
+            &mut self,
+            target_account_id: AccountId,
+            token_year: Year,
+            token_amount: CarbonUnit,
+            strategy: AllocationStrategy,
+        ) -> Result<(), OperationError> {
+            self.transfer_by_year(
+                target_account_id,
+                token_year,
+                token_amount,
+                strategy,
+                GenericVec::new(),
+                None,
+            )
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_by_year_with_memo(
+            &mut self,
+            target_account_id: AccountId,
+            token_year: Year,
+            token_amount: CarbonUnit,
+            strategy: AllocationStrategy,
+            memo: TransferMemo,
+        ) -> Result<(), OperationError> {
+            self.transfer_by_year(
+                target_account_id,
+                token_year,
+                token_amount,
+                strategy,
+                GenericVec::new(),
+                Some(memo),
+            )
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_by_year_with_data(
+            &mut self,
+            target_account_id: AccountId,
+            token_year: Year,
+            token_amount: CarbonUnit,
+            strategy: AllocationStrategy,
+            memo: Option<TransferMemo>,
+            data: GenericVec<u8>,
+        ) -> Result<(), OperationError> {
+            self.transfer_by_year(
+                target_account_id,
+                token_year,
+                token_amount,
+                strategy,
+                data,
+                memo,
+            )
+        }
+
+        #[ink(message)]
+        pub fn own_token_allocation_preview_by_year(
+            &mut self,
+            token_year: Year,
+            token_amount: CarbonUnit,
+            strategy: AllocationStrategy,
+        ) -> Result<TokenEditions, OperationError> {
+            let account_context = self.env().caller();
+
+            self.tokens.plan_allocation_by_year(
+                account_context,
+                token_year,
+                token_amount,
+                &strategy,
+            )
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_compounded(
+            &mut self,
+            target_account_id: AccountId,
+            params: TokenEditions,
+        ) -> Result<(), OperationError> {
+            self.transfer_compounded(target_account_id, params, GenericVec::new(), None)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_compounded_with_memo(
+            &mut self,
+            target_account_id: AccountId,
+            params: TokenEditions,
+            memo: TransferMemo,
+        ) -> Result<(), OperationError> {
+            self.transfer_compounded(target_account_id, params, GenericVec::new(), Some(memo))
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_compounded_with_data(
+            &mut self,
+            target_account_id: AccountId,
+            params: TokenEditions,
+            memo: Option<TransferMemo>,
+            data: GenericVec<u8>,
+        ) -> Result<(), OperationError> {
+            self.transfer_compounded(target_account_id, params, data, memo)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_batch(
+            &mut self,
+            params: TokenTransferBatch,
+        ) -> Result<(), OperationError> {
+            self.transfer_batch(params, GenericVec::new(), None)
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_batch_with_memo(
+            &mut self,
+            params: TokenTransferBatch,
+            memo: TransferMemo,
+        ) -> Result<(), OperationError> {
+            self.transfer_batch(params, GenericVec::new(), Some(memo))
+        }
+
+        #[ink(message)]
+        pub fn own_token_transfer_batch_with_data(
+            &mut self,
+            params: TokenTransferBatch,
+            memo: Option<TransferMemo>,
+            data: GenericVec<u8>,
+        ) -> Result<(), OperationError> {
+            self.transfer_batch(params, data, memo)
+        }
+
+        #[ink(message)]
+        pub fn any_retirement_report_get_by_id(
+            &mut self,
+            retirement_id: RetirementId,
+        ) -> Result<RetirementReport, OperationError> {
+            self.retirements.get_report_by_id(retirement_id)
+        }
+
+        #[ink(message)]
+        pub fn any_retirement_report_get_last(
+            &mut self,
+        ) -> Result<RetirementReport, OperationError> {
+            self.retirements.get_last_report()
+        }
+
+        #[ink(message)]
+        pub fn any_retirement_report_page(
+            &mut self,
+            start: Option<RetirementId>,
+            limit: u32,
+        ) -> Page<RetirementId, RetirementReport> {
+            self.retirements.get_report_page(start, limit)
+        }
+
+        #[ink(message)]
+        pub fn own_retirement_report_page(
+            &mut self,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, RetirementReport> {
+            let account_context = self.env().caller();
+
+            self.retirements
+                .get_account_report_page(account_context, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn own_retirement_report_get_all(&mut self) -> RetirementReports {
+            let account_context = self.env().caller();
+
+            self.retirements.get_account_report(account_context)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_get_all(
+            &mut self,
+            account_id: AccountId,
+        ) -> TokenBalances {
+            self.tokens.get_account_balances(account_id)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_page(
+            &mut self,
+            account_id: AccountId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, TokenBalanceDetail> {
+            self.tokens
+                .get_account_balance_page(account_id, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_get_by_id(
+            &mut self,
+            account_id: AccountId,
+            token_id: TokenId,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_account_balance_by_id(account_id, token_id)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_get_by_year(
+            &mut self,
+            account_id: AccountId,
+            year: Year,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens.get_account_balance_by_year(account_id, year)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_get_total(&mut self, account_id: AccountId) -> CarbonUnit {
+            self.tokens.get_account_total_balance(account_id)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_get_at_block(
+            &mut self,
+            account_id: AccountId,
+            token_id: TokenId,
+            block: BlockNumber,
+        ) -> Result<CarbonUnit, OperationError> {
+            self.tokens
+                .get_account_balance_at(account_id, token_id, block)
+        }
+
+        #[ink(message)]
+        pub fn any_account_token_balance_get_at_snapshot(
+            &mut self,
+            account_id: AccountId,
+            token_id: TokenId,
+            snapshot_id: SnapshotId,
+        ) -> Result<CarbonUnit, OperationError> {
+            let snapshot = self.tokens.get_snapshot_by_id(snapshot_id)?;
+
+            self.tokens
+                .get_account_balance_at(account_id, token_id, snapshot.block_number)
+        }
+
+        #[ink(message)]
+        pub fn any_account_retirement_report_get_all(
+            &mut self,
+            account_id: AccountId,
+        ) -> RetirementReports {
+            self.retirements.get_account_report(account_id)
+        }
+
+        #[ink(message)]
+        pub fn any_account_retirement_report_page(
+            &mut self,
+            account_id: AccountId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<u32, RetirementReport> {
+            self.retirements
+                .get_account_report_page(account_id, start, limit)
+        }
+
+        #[ink(message)]
+        pub fn own_token_retire_by_id(
+            &mut self,
+            token_id: TokenId,
+            retirement_amount: CarbonUnit,
+        ) -> Result<RetirementId, OperationError> {
+            let account_context = self.env().caller();
+
+            self.retire_edition(
+                account_context,
+                account_context,
+                token_id,
+                retirement_amount,
+            )
         }
 
         #[ink(message)]
-        pub fn gov_token_mint_approve(
+        pub fn own_token_retire_by_year(
             &mut self,
-            registry_id: RegistryId,
-        ) -> Result<(), OperationError> {
-            if self.env().caller() != *self.governor {
-                return Err(OperationError::Unauthorized);
-            }
+            token_year: Year,
+            retirement_amount: CarbonUnit,
+            strategy: AllocationStrategy,
+        ) -> Result<GenericVec<RetirementId>, OperationError> {
+            let account_context = self.env().caller();
+            let allocation = self.tokens.plan_allocation_by_year(
+                account_context,
+                token_year,
+                retirement_amount,
+                &strategy,
+            )?;
+            let mut reports = GenericVec::new();
 
-            let (minter_id, target_account_id, token_id, token_amount) =
-                self.tokens.approve_pending_mint(&registry_id)?;
-            self.env().emit_event(TokenMintApproved {
-                from: *self.governor,
-                to: minter_id,
-                registry_id,
-                id: token_id,
-            });
-            let mut editions = GenericVec::new();
-            editions.push(TokenEdition {
-                id: token_id,
-                amount: token_amount,
-            });
-            self.env().emit_event(TokenTransferred {
-                from: *self.governor,
-                to: target_account_id,
-                editions,
-            });
+            for token_edition in allocation {
+                reports.push(self.retire_edition(
+                    account_context,
+                    account_context,
+                    token_edition.id,
+                    token_edition.amount,
+                )?);
+            }
 
-            Ok(())
+            Ok(reports)
         }
 
         #[ink(message)]
-        pub fn gov_token_mint_deny(
+        pub fn gov_ledger_retention_set(
             &mut self,
-            registry_id: RegistryId,
+            retention_blocks: Option<BlockNumber>,
         ) -> Result<(), OperationError> {
             if self.env().caller() != *self.governor {
                 return Err(OperationError::Unauthorized);
             }
 
-            let to = self.tokens.deny_pending_mint(&registry_id)?;
-            self.env().emit_event(TokenMintDenied {
+            self.ledger.set_retention(retention_blocks);
+            self.env().emit_event(ConfigurationChanged {
+                version: EVENT_SCHEMA_VERSION,
                 from: *self.governor,
-                to,
-                registry_id,
+                key: ConfigurationKey::LedgerRetention,
+                block_number: self.env().block_number(),
             });
 
             Ok(())
         }
 
         #[ink(message)]
-        pub fn any_token_mint_info_get_last(&mut self) -> Result<TokenDetail, OperationError> {
-            self.tokens.get_last_minted_edition_info()
+        pub fn any_ledger_retention_get(&mut self) -> Option<BlockNumber> {
+            self.ledger.get_retention()
         }
 
         #[ink(message)]
-        pub fn any_token_mint_info_get_by_id(
+        pub fn any_ledger_entry_get_by_sequence(
             &mut self,
-            token_id: TokenId,
-        ) -> Result<TokenDetail, OperationError> {
-            self.tokens.get_minted_edition_by_id(token_id)
-        }
-
-        #[ink(message)]
-        pub fn any_token_supply_get_total(&mut self) -> CarbonUnit {
-            self.tokens.get_total_supply()
+            sequence: LedgerSequence,
+        ) -> Result<LedgerEntry, OperationError> {
+            self.ledger.get_entry_by_sequence(sequence)
         }
 
         #[ink(message)]
-        pub fn any_token_supply_get_by_year(
+        pub fn any_ledger_entry_page_by_account(
             &mut self,
-            year: Year,
-        ) -> Result<CarbonUnit, OperationError> {
-            self.tokens.get_supply_by_year(year)
+            account_id: AccountId,
+            start: Option<LedgerSequence>,
+            limit: u32,
+        ) -> Page<LedgerSequence, LedgerEntry> {
+            self.ledger.get_account_entry_page(account_id, start, limit)
         }
 
         #[ink(message)]
-        pub fn any_token_supply_get_by_id(
+        pub fn any_ledger_entry_page_by_id(
             &mut self,
             token_id: TokenId,
-        ) -> Result<CarbonUnit, OperationError> {
-            self.tokens.get_supply_by_id(token_id)
+            start: Option<LedgerSequence>,
+            limit: u32,
+        ) -> Page<LedgerSequence, LedgerEntry> {
+            self.ledger.get_edition_entry_page(token_id, start, limit)
         }
+
         #[ink(message)]
-        pub fn any_token_retired_supply_get_total(&mut self) -> CarbonUnit {
-            self.tokens.get_total_retired()
+        pub fn gov_pool_create(
+            &mut self,
+            params: PoolCreateParams,
+        ) -> Result<PoolId, OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
+
+            let pool_id = self.pools.create(params)?;
+            self.env().emit_event(PoolCreated {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: pool_id,
+                detail: self.pools.get_pool_by_id(pool_id)?,
+            });
+
+            Ok(pool_id)
         }
 
         #[ink(message)]
-        pub fn any_token_retired_supply_get_by_year(
+        pub fn any_pool_info_get_by_id(
             &mut self,
-            year: Year,
-        ) -> Result<CarbonUnit, OperationError> {
-            self.tokens.get_retired_by_year(year)
+            pool_id: PoolId,
+        ) -> Result<PoolDetail, OperationError> {
+            self.pools.get_pool_by_id(pool_id)
         }
 
         #[ink(message)]
-        pub fn any_token_retired_supply_get_by_id(
+        pub fn any_pool_holdings_get_by_id(
             &mut self,
-            token_id: TokenId,
-        ) -> Result<CarbonUnit, OperationError> {
-            self.tokens.get_retired_by_id(token_id)
+            pool_id: PoolId,
+        ) -> Result<TokenBalances, OperationError> {
+            self.pools.get_pool_by_id(pool_id)?;
+
+            Ok(self.tokens.get_account_balances(get_pool_address(pool_id)))
         }
 
         #[ink(message)]
-        pub fn own_token_balance_get_all(&mut self) -> TokenBalances {
+        pub fn own_pool_balance_get_by_id(
+            &mut self,
+            pool_id: PoolId,
+        ) -> Result<CarbonUnit, OperationError> {
             let account_context = self.env().caller();
 
-            self.tokens.get_account_balances(account_context)
+            self.pools.get_account_balance(pool_id, account_context)
         }
 
         #[ink(message)]
-        pub fn own_token_balance_get_by_id(
+        pub fn own_pool_deposit(
             &mut self,
+            pool_id: PoolId,
             token_id: TokenId,
-        ) -> Result<CarbonUnit, OperationError> {
+            token_amount: CarbonUnit,
+        ) -> Result<(), OperationError> {
             let account_context = self.env().caller();
+            let pool_account_id = get_pool_address(pool_id);
+            let token_detail = self.tokens.get_edition_details(token_id)?;
+            self.pools.check_eligibility(pool_id, &token_detail)?;
+            let edition = self.tokens.transfer_token_by_id(
+                account_context,
+                pool_account_id,
+                token_id,
+                token_amount,
+            )?;
+            self.pools
+                .mint_units(pool_id, account_context, token_amount)?;
+            let mut editions = GenericVec::new();
+            editions.push(edition);
+            self.ledger.record(
+                LedgerEntryKind::Transfer,
+                account_context,
+                pool_account_id,
+                &editions,
+            );
+            self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
+                from: account_context,
+                to: pool_account_id,
+                editions: editions.clone(),
+                memo: None,
+            });
+            self.env().emit_event(PoolDeposited {
+                version: EVENT_SCHEMA_VERSION,
+                from: account_context,
+                id: pool_id,
+                editions,
+                units: token_amount,
+                detail: self.pools.get_pool_by_id(pool_id)?,
+            });
 
-            self.tokens
-                .get_account_balance_by_id(account_context, token_id)
+            Ok(())
         }
 
         #[ink(message)]
-        pub fn own_token_balance_get_by_year(
+        pub fn own_pool_redeem(
             &mut self,
-            year: Year,
-        ) -> Result<CarbonUnit, OperationError> {
+            pool_id: PoolId,
+            token_id: TokenId,
+            token_amount: CarbonUnit,
+        ) -> Result<(), OperationError> {
             let account_context = self.env().caller();
+            let pool_account_id = get_pool_address(pool_id);
+            let pool_detail = self.pools.get_pool_by_id(pool_id)?;
+            let fee = self.pools.get_redemption_fee(pool_id, token_amount)?;
+            let units = self.pools.get_required_units(pool_id, token_amount)?;
 
-            self.tokens
-                .get_account_balance_by_year(account_context, year)
+            if self.pools.get_account_balance(pool_id, account_context)? < units.saturating_add(fee)
+            {
+                return Err(OperationError::InsufficientPoolUnit);
+            }
+
+            let edition = self.tokens.transfer_token_by_id(
+                pool_account_id,
+                account_context,
+                token_id,
+                token_amount,
+            )?;
+            self.pools
+                .redeem_units(pool_id, account_context, units, token_amount)?;
+
+            if fee > 0 {
+                self.pools.transfer_units(
+                    pool_id,
+                    account_context,
+                    pool_detail.fee_recipient,
+                    fee,
+                )?;
+            }
+
+            let mut editions = GenericVec::new();
+            editions.push(edition);
+            self.notify_token_receiver(
+                pool_account_id,
+                account_context,
+                &editions,
+                &GenericVec::new(),
+            )?;
+            self.ledger.record(
+                LedgerEntryKind::Transfer,
+                pool_account_id,
+                account_context,
+                &editions,
+            );
+            self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
+                from: pool_account_id,
+                to: account_context,
+                editions: editions.clone(),
+                memo: None,
+            });
+            self.env().emit_event(PoolRedeemed {
+                version: EVENT_SCHEMA_VERSION,
+                to: account_context,
+                id: pool_id,
+                editions,
+                units,
+                fee,
+                detail: self.pools.get_pool_by_id(pool_id)?,
+            });
+
+            Ok(())
         }
 
         #[ink(message)]
-        pub fn own_token_balance_get_total(&mut self) -> CarbonUnit {
+        pub fn own_pool_retire(
+            &mut self,
+            pool_id: PoolId,
+            retirement_amount: CarbonUnit,
+        ) -> Result<GenericVec<RetirementId>, OperationError> {
             let account_context = self.env().caller();
+            let pool_account_id = get_pool_address(pool_id);
 
-            self.tokens.get_account_total_balance(account_context)
+            if retirement_amount == 0 {
+                return Err(OperationError::CannotTransferZeroCarbonUnit);
+            }
+
+            if self.pools.get_account_balance(pool_id, account_context)? < retirement_amount {
+                return Err(OperationError::InsufficientPoolUnit);
+            }
+
+            let backed_amount = self.pools.get_backed_amount(pool_id, retirement_amount)?;
+            let mut holdings = self.tokens.get_account_balances(pool_account_id);
+            holdings.retain(|holding| self.tokens.is_active(holding.detail.id));
+            holdings.sort_by_key(|holding| (holding.detail.year, holding.detail.id));
+            let mut reports = GenericVec::new();
+            let mut remaining_amount_to_retire = backed_amount;
+
+            for holding in holdings {
+                if remaining_amount_to_retire == 0 {
+                    break;
+                }
+
+                let edition_amount = holding.balance.min(remaining_amount_to_retire);
+                reports.push(self.retire_edition(
+                    pool_account_id,
+                    account_context,
+                    holding.detail.id,
+                    edition_amount,
+                )?);
+                remaining_amount_to_retire -= edition_amount;
+            }
+
+            if remaining_amount_to_retire > 0 {
+                return Err(OperationError::InsufficientPoolBacking);
+            }
+
+            self.pools
+                .redeem_units(pool_id, account_context, retirement_amount, backed_amount)?;
+            self.env().emit_event(PoolRetired {
+                version: EVENT_SCHEMA_VERSION,
+                from: account_context,
+                id: pool_id,
+                amount: retirement_amount,
+                reports: reports.clone(),
+                detail: self.pools.get_pool_by_id(pool_id)?,
+            });
+
+            Ok(reports)
         }
 
-        #[ink(message)]
-        pub fn own_token_transfer_all(
+        fn transfer_all(
             &mut self,
             target_account_id: AccountId,
+            data: GenericVec<u8>,
+            memo: Option<TransferMemo>,
         ) -> Result<(), OperationError> {
             let account_context = self.env().caller();
             let editions = self
                 .tokens
                 .transfer_token_all(account_context, target_account_id)?;
+            self.notify_token_receiver(account_context, target_account_id, &editions, &data)?;
+            self.ledger.record(
+                LedgerEntryKind::Transfer,
+                account_context,
+                target_account_id,
+                &editions,
+            );
             self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
                 from: account_context,
                 to: target_account_id,
                 editions,
+                memo,
             });
 
             Ok(())
         }
 
-        #[ink(message)]
-        pub fn own_token_transfer_by_id(
+        fn transfer_by_id(
             &mut self,
             target_account_id: AccountId,
             token_id: TokenId,
             token_amount: CarbonUnit,
+            data: GenericVec<u8>,
+            memo: Option<TransferMemo>,
         ) -> Result<(), OperationError> {
             let account_context = self.env().caller();
             let index = self.tokens.transfer_token_by_id(
@@ -380,21 +3081,32 @@ pub mod contract {
             )?;
             let mut editions = GenericVec::new();
             editions.push(index);
+            self.notify_token_receiver(account_context, target_account_id, &editions, &data)?;
+            self.ledger.record(
+                LedgerEntryKind::Transfer,
+                account_context,
+                target_account_id,
+                &editions,
+            );
             self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
                 from: account_context,
                 to: target_account_id,
                 editions,
+                memo,
             });
 
             Ok(())
         }
 
-        #[ink(message)]
-        pub fn own_token_transfer_by_year(
+        fn transfer_by_year(
             &mut self,
             target_account_id: AccountId,
             token_year: Year,
             token_amount: CarbonUnit,
+            strategy: AllocationStrategy,
+            data: GenericVec<u8>,
+            memo: Option<TransferMemo>,
         ) -> Result<(), OperationError> {
             let account_context = self.env().caller();
             let editions = self.tokens.transfer_token_by_year(
@@ -402,78 +3114,320 @@ pub mod contract {
                 target_account_id,
                 token_year,
                 token_amount,
+                &strategy,
             )?;
+            self.notify_token_receiver(account_context, target_account_id, &editions, &data)?;
+            self.ledger.record(
+                LedgerEntryKind::Transfer,
+                account_context,
+                target_account_id,
+                &editions,
+            );
             self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
                 from: account_context,
                 to: target_account_id,
                 editions,
+                memo,
             });
 
             Ok(())
         }
 
-        #[ink(message)]
-        pub fn own_token_transfer_compounded(
+        fn transfer_compounded(
             &mut self,
             target_account_id: AccountId,
             params: TokenEditions,
+            data: GenericVec<u8>,
+            memo: Option<TransferMemo>,
         ) -> Result<(), OperationError> {
             let account_context = self.env().caller();
             self.tokens
                 .transfer_token_compounded(account_context, target_account_id, &params)?;
+            self.notify_token_receiver(account_context, target_account_id, &params, &data)?;
+            self.ledger.record(
+                LedgerEntryKind::Transfer,
+                account_context,
+                target_account_id,
+                &params,
+            );
             self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
                 from: account_context,
                 to: target_account_id,
                 editions: params,
+                memo,
             });
 
             Ok(())
         }
 
-        #[ink(message)]
-        pub fn any_retirement_report_get_by_id(
+        fn transfer_batch(
             &mut self,
-            retirement_id: RetirementId,
-        ) -> Result<RetirementReport, OperationError> {
-            self.retirements.get_report_by_id(retirement_id)
+            params: TokenTransferBatch,
+            data: GenericVec<u8>,
+            memo: Option<TransferMemo>,
+        ) -> Result<(), OperationError> {
+            let account_context = self.env().caller();
+            self.tokens.transfer_token_batch(account_context, &params)?;
+
+            for (index, (target_account_id, editions)) in params.iter().enumerate() {
+                if self
+                    .notify_token_receiver(account_context, *target_account_id, editions, &data)
+                    .is_err()
+                {
+                    return Err(OperationError::BatchTransferFailed(index as u32));
+                }
+            }
+
+            for (target_account_id, editions) in params {
+                self.ledger.record(
+                    LedgerEntryKind::Transfer,
+                    account_context,
+                    target_account_id,
+                    &editions,
+                );
+                self.env().emit_event(TokenTransferred {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: account_context,
+                    to: target_account_id,
+                    editions,
+                    memo: memo.clone(),
+                });
+            }
+
+            Ok(())
         }
 
-        #[ink(message)]
-        pub fn any_retirement_report_get_last(
+        fn anchor_documents(
             &mut self,
-        ) -> Result<RetirementReport, OperationError> {
-            self.retirements.get_last_report()
+            subject: DocumentSubject,
+            submitter: AccountId,
+            documents: GenericVec<DocumentAttachParams>,
+        ) -> Result<(), OperationError> {
+            for document in self.documents.append(subject, submitter, documents)? {
+                self.env().emit_event(DocumentAnchored {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: submitter,
+                    subject,
+                    document,
+                });
+            }
+
+            Ok(())
         }
 
-        #[ink(message)]
-        pub fn own_retirement_report_get_all(&mut self) -> RetirementReports {
-            let account_context = self.env().caller();
+        fn review_project(
+            &mut self,
+            project_id: ProjectId,
+            status: ProjectStatus,
+        ) -> Result<(), OperationError> {
+            if self.env().caller() != *self.governor {
+                return Err(OperationError::Unauthorized);
+            }
 
-            self.retirements.get_account_report(account_context)
+            let detail = self.projects.review(project_id, status)?;
+            self.env().emit_event(ProjectStatusChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: detail.id,
+                status: detail.status,
+                block_number: self.env().block_number(),
+            });
+
+            Ok(())
         }
 
-        #[ink(message)]
-        pub fn own_token_retire_by_id(
+        fn record_supplement_issuance(
+            &mut self,
+            issuance: &TokenIssuance,
+            buffer_amount: CarbonUnit,
+        ) {
+            let token_id = issuance.token_id;
+            let beneficiary = issuance.beneficiary;
+            let mut editions = GenericVec::new();
+            editions.push(TokenEdition {
+                id: token_id,
+                amount: issuance.amount - buffer_amount,
+            });
+            self.ledger.record(
+                LedgerEntryKind::Mint,
+                *self.governor,
+                beneficiary,
+                &editions,
+            );
+            self.env().emit_event(TokenTransferred {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                to: beneficiary,
+                editions,
+                memo: None,
+            });
+
+            if buffer_amount > 0 {
+                self.buffer.record_withheld(token_id, buffer_amount);
+                let mut buffer_editions = GenericVec::new();
+                buffer_editions.push(TokenEdition {
+                    id: token_id,
+                    amount: buffer_amount,
+                });
+                self.ledger.record(
+                    LedgerEntryKind::Mint,
+                    *self.governor,
+                    get_buffer_address(),
+                    &buffer_editions,
+                );
+                self.env().emit_event(TokenTransferred {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: *self.governor,
+                    to: get_buffer_address(),
+                    editions: buffer_editions,
+                    memo: None,
+                });
+                self.env().emit_event(BufferWithheld {
+                    version: EVENT_SCHEMA_VERSION,
+                    from: *self.governor,
+                    id: token_id,
+                    amount: buffer_amount,
+                    block_number: self.env().block_number(),
+                });
+            }
+        }
+
+        fn invalidate_edition(
+            &mut self,
+            token_id: TokenId,
+            reason: String,
+            evidence_hash: Hash,
+        ) -> Result<(), OperationError> {
+            let detail = self.tokens.invalidate(token_id, reason, evidence_hash)?;
+            let invalidation = match &detail.invalidation {
+                None => return Err(OperationError::BlockchainCorrupted),
+                Some(invalidation) => invalidation.clone(),
+            };
+            self.custodians
+                .record_cancellation(detail.minter, invalidation.cancelled);
+            self.retirements.flag_invalidated_edition(token_id);
+            self.env().emit_event(TokenStatusChanged {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: token_id,
+                status: detail.status,
+                block_number: self.env().block_number(),
+            });
+            self.env().emit_event(TokenInvalidated {
+                version: EVENT_SCHEMA_VERSION,
+                from: *self.governor,
+                id: token_id,
+                registry_id_hash: hash_registry_id(&detail.registry_id),
+                detail,
+            });
+
+            if invalidation.remaining_holders > 0 {
+                self.write_down_invalidated_holders(token_id)?;
+            }
+
+            Ok(())
+        }
+
+        fn write_down_invalidated_holders(
+            &mut self,
+            token_id: TokenId,
+        ) -> Result<(), OperationError> {
+            let caller = self.env().caller();
+            let (detail, holders) = self.tokens.write_down_invalidated_holders(token_id)?;
+
+            for holder in &holders {
+                if let Some(pool_id) = get_pool_id(&holder.account_id) {
+                    if let Some(pool_detail) = self.pools.write_down(pool_id, holder.balance) {
+                        self.env().emit_event(PoolWrittenDown {
+                            version: EVENT_SCHEMA_VERSION,
+                            from: caller,
+                            id: pool_id,
+                            token_id,
+                            amount: holder.balance,
+                            detail: pool_detail,
+                        });
+                    }
+                }
+
+                let mut editions = GenericVec::new();
+                editions.push(TokenEdition {
+                    id: token_id,
+                    amount: holder.balance,
+                });
+                self.ledger.record(
+                    LedgerEntryKind::Cancellation,
+                    holder.account_id,
+                    get_blackhole_address(),
+                    &editions,
+                );
+            }
+
+            let remaining_holders = match detail.invalidation {
+                None => return Err(OperationError::BlockchainCorrupted),
+                Some(invalidation) => invalidation.remaining_holders,
+            };
+            self.env().emit_event(TokenHoldersWrittenDown {
+                version: EVENT_SCHEMA_VERSION,
+                from: caller,
+                id: token_id,
+                holders,
+                remaining_holders,
+            });
+
+            Ok(())
+        }
+
+        fn notify_token_receiver(
+            &mut self,
+            account_id: AccountId,
+            target_account_id: AccountId,
+            editions: &TokenEditions,
+            data: &GenericVec<u8>,
+        ) -> Result<(), OperationError> {
+            let operator = self.env().caller();
+
+            notify_receiver(operator, account_id, target_account_id, editions, data)
+        }
+
+        fn retire_edition(
             &mut self,
+            holder_account_id: AccountId,
+            beneficiary: AccountId,
             token_id: TokenId,
             retirement_amount: CarbonUnit,
         ) -> Result<RetirementId, OperationError> {
-            let account_context = self.env().caller();
-            self.tokens
-                .retire_token_id(account_context, token_id, retirement_amount)?;
+            let retired_serials =
+                self.tokens
+                    .retire_token_id(holder_account_id, token_id, retirement_amount)?;
             let token_detail = self.tokens.get_edition_details(token_id)?;
             let token_detail = TokenBalanceDetail {
                 balance: retirement_amount,
                 detail: token_detail,
             };
-            let retirement_info = self
-                .retirements
-                .insert_new_report(account_context, &token_detail);
-            let retirement_id = retirement_info.id;
+            let report =
+                self.retirements
+                    .insert_new_report(beneficiary, &token_detail, retired_serials);
+            let mut editions = GenericVec::new();
+            editions.push(TokenEdition {
+                id: token_id,
+                amount: retirement_amount,
+            });
+            self.ledger.record(
+                LedgerEntryKind::Retirement,
+                holder_account_id,
+                get_blackhole_address(),
+                &editions,
+            );
+            let retirement_id = report.id;
             self.env().emit_event(TokenRetired {
+                version: EVENT_SCHEMA_VERSION,
                 from: self.env().account_id(),
-                to: account_context,
-                info: retirement_info,
+                to: beneficiary,
+                registry_id_hash: hash_registry_id(&report.registry_id),
+                report,
+                detail: token_detail.detail,
             });
 
             Ok(retirement_id)
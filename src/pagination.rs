@@ -0,0 +1,149 @@
+use crate::{Decode, Encode, GenericVec, SpreadLayout, StorageHashMap, StorageVec};
+use ink_storage::traits::PackedLayout;
+
+pub const MAX_PAGE_LIMIT: u32 = 100;
+pub const MAX_PAGE_SCAN: u32 = 1_000;
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Page<C, T> {
+    pub items: GenericVec<T>,
+    pub next_cursor: Option<C>,
+}
+
+pub fn clamp_limit(limit: u32) -> u32 {
+    limit.clamp(1, MAX_PAGE_LIMIT)
+}
+
+pub fn paginate_indexed<T, F>(len: u32, start: Option<u32>, limit: u32, load: F) -> Page<u32, T>
+where
+    F: Fn(u32) -> Option<T>,
+{
+    let start = start.unwrap_or(0);
+    let end = start.saturating_add(clamp_limit(limit)).min(len);
+    let items = (start..end).filter_map(load).collect::<GenericVec<T>>();
+    let next_cursor = if end < len { Some(end) } else { None };
+
+    Page { items, next_cursor }
+}
+
+/// Keys in insertion order, so map pages can be cut by position. Removing a key moves
+/// the last key into its slot.
+#[derive(Debug, SpreadLayout)]
+pub struct KeyIndex<K: Ord + Clone + PackedLayout> {
+    keys: StorageVec<K>,
+    positions: StorageHashMap<K, u32>,
+}
+
+impl<K: Ord + Clone + PackedLayout> Default for KeyIndex<K> {
+    fn default() -> Self {
+        Self {
+            keys: StorageVec::new(),
+            positions: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<K: Ord + Clone + PackedLayout> KeyIndex<K> {
+    pub fn len(&self) -> u32 {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn get(&self, position: u32) -> Option<&K> {
+        self.keys.get(position)
+    }
+
+    pub fn insert(&mut self, key: K) {
+        if self.positions.contains_key(&key) {
+            return;
+        }
+
+        self.positions.insert(key.clone(), self.keys.len());
+        self.keys.push(key);
+    }
+
+    pub fn remove(&mut self, key: &K) {
+        let position = match self.positions.take(key) {
+            None => return,
+            Some(position) => position,
+        };
+        self.keys.swap_remove_drop(position);
+
+        if let Some(moved_key) = self.keys.get(position) {
+            self.positions.insert(moved_key.clone(), position);
+        }
+    }
+
+    pub fn get_page<T, F>(&self, start: Option<u32>, limit: u32, load: F) -> Page<u32, T>
+    where
+        F: Fn(&K) -> Option<T>,
+    {
+        paginate_indexed(self.keys.len(), start, limit, |position| {
+            load(self.keys.get(position)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paginate_indexed_cursor_stops_at_last_page() {
+        let first_page = paginate_indexed(5, None, 2, Some);
+        assert_eq!(first_page.items, vec![0, 1]);
+        assert_eq!(first_page.next_cursor, Some(2));
+
+        let last_page = paginate_indexed(5, Some(4), 2, Some);
+        assert_eq!(last_page.items, vec![4]);
+        assert_eq!(last_page.next_cursor, None);
+
+        let exact_page = paginate_indexed(4, Some(2), 2, Some);
+        assert_eq!(exact_page.items, vec![2, 3]);
+        assert_eq!(exact_page.next_cursor, None);
+
+        let past_end = paginate_indexed(4, Some(9), 2, Some);
+        assert!(past_end.items.is_empty());
+        assert_eq!(past_end.next_cursor, None);
+    }
+
+    #[test]
+    fn paginate_indexed_clamps_limit() {
+        let page = paginate_indexed(MAX_PAGE_LIMIT + 10, None, 0, Some);
+        assert_eq!(page.items, vec![0]);
+
+        let page = paginate_indexed(MAX_PAGE_LIMIT + 10, None, u32::MAX, Some);
+        assert_eq!(page.items.len() as u32, MAX_PAGE_LIMIT);
+        assert_eq!(page.next_cursor, Some(MAX_PAGE_LIMIT));
+    }
+
+    #[test]
+    fn key_index_moves_last_key_into_removed_slot() {
+        let mut index = KeyIndex::default();
+
+        for key in [10u32, 20, 30, 40] {
+            index.insert(key);
+        }
+
+        index.insert(20);
+        index.remove(&20);
+        index.remove(&99);
+
+        assert_eq!(index.len(), 3);
+        assert_eq!(
+            index.get_page(None, 10, |key| Some(*key)).items,
+            vec![10, 40, 30]
+        );
+
+        index.remove(&30);
+        index.remove(&10);
+        assert_eq!(index.get_page(None, 10, |key| Some(*key)).items, vec![40]);
+
+        index.remove(&40);
+        assert!(index.is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
+use crate::token::Detail as TokenDetail;
+use crate::{
+    CarbonUnit, Decode, Encode, GenericVec, OperationError, PoolId, SpreadLayout, StorageBox,
+    StorageHashMap, String, Year,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+pub const FEE_BASIS_POINTS: CarbonUnit = 10_000;
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct CreateParams {
+    pub name: String,
+    pub min_year: Option<Year>,
+    pub allowed_minters: GenericVec<AccountId>,
+    pub allowed_methodologies: GenericVec<String>,
+    pub redemption_fee_bps: u16,
+    pub fee_recipient: AccountId,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Detail {
+    pub id: PoolId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub name: String,
+    pub min_year: Option<Year>,
+    pub allowed_minters: GenericVec<AccountId>,
+    pub allowed_methodologies: GenericVec<String>,
+    pub redemption_fee_bps: u16,
+    pub fee_recipient: AccountId,
+    pub supply: CarbonUnit,
+    pub shortfall: CarbonUnit,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Registry {
+    next_pool_id: PoolId,
+    pools: StorageHashMap<PoolId, Detail>,
+    balances: StorageHashMap<PoolId, StorageBox<StorageHashMap<AccountId, CarbonUnit>>>,
+}
+
+impl Detail {
+    pub fn is_eligible(&self, token_detail: &TokenDetail) -> bool {
+        if let Some(min_year) = self.min_year {
+            if token_detail.year < min_year {
+                return false;
+            }
+        }
+
+        if !self.allowed_methodologies.is_empty()
+            && !self
+                .allowed_methodologies
+                .contains(&token_detail.attributes.methodology)
+        {
+            return false;
+        }
+
+        self.allowed_minters.is_empty() || self.allowed_minters.contains(&token_detail.minter)
+    }
+}
+
+impl Registry {
+    pub fn take_next_pool_id(&mut self) -> PoolId {
+        let next_pool_id = self.next_pool_id;
+        self.next_pool_id += 1;
+
+        next_pool_id
+    }
+
+    pub fn create(&mut self, params: CreateParams) -> Result<PoolId, OperationError> {
+        if params.redemption_fee_bps as CarbonUnit > FEE_BASIS_POINTS {
+            return Err(OperationError::InvalidPoolParameters);
+        }
+
+        let pool_id = self.take_next_pool_id();
+        let detail = Detail {
+            id: pool_id,
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            name: params.name,
+            min_year: params.min_year,
+            allowed_minters: params.allowed_minters,
+            allowed_methodologies: params.allowed_methodologies,
+            redemption_fee_bps: params.redemption_fee_bps,
+            fee_recipient: params.fee_recipient,
+            supply: 0,
+            shortfall: 0,
+        };
+        self.pools.insert(pool_id, detail);
+        self.balances
+            .insert(pool_id, StorageBox::new(StorageHashMap::new()));
+
+        Ok(pool_id)
+    }
+
+    pub fn get_pool_by_id(&self, pool_id: PoolId) -> Result<Detail, OperationError> {
+        match self.pools.get(&pool_id) {
+            None => Err(OperationError::PoolNotFound),
+            Some(detail) => Ok(detail.clone()),
+        }
+    }
+
+    pub fn check_eligibility(
+        &self,
+        pool_id: PoolId,
+        token_detail: &TokenDetail,
+    ) -> Result<(), OperationError> {
+        match self.pools.get(&pool_id) {
+            None => Err(OperationError::PoolNotFound),
+            Some(detail) => {
+                if !detail.is_eligible(token_detail) {
+                    return Err(OperationError::TokenNotEligibleForPool);
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_redemption_fee(
+        &self,
+        pool_id: PoolId,
+        amount: CarbonUnit,
+    ) -> Result<CarbonUnit, OperationError> {
+        match self.pools.get(&pool_id) {
+            None => Err(OperationError::PoolNotFound),
+            Some(detail) => Ok(amount * detail.redemption_fee_bps as CarbonUnit / FEE_BASIS_POINTS),
+        }
+    }
+
+    pub fn get_account_balance(
+        &self,
+        pool_id: PoolId,
+        account_id: AccountId,
+    ) -> Result<CarbonUnit, OperationError> {
+        match self.balances.get(&pool_id) {
+            None => Err(OperationError::PoolNotFound),
+            Some(pool_balances) => match pool_balances.get(&account_id) {
+                None => Ok(0),
+                Some(pool_balance) => Ok(*pool_balance),
+            },
+        }
+    }
+
+    pub fn mint_units(
+        &mut self,
+        pool_id: PoolId,
+        account_id: AccountId,
+        amount: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        if !self.pools.contains_key(&pool_id) {
+            return Err(OperationError::PoolNotFound);
+        }
+
+        let pool_balances = self.balances.get_mut(&pool_id).unwrap();
+
+        if let Some(pool_balance) = pool_balances.get_mut(&account_id) {
+            *pool_balance += amount;
+        } else {
+            pool_balances.insert(account_id, amount);
+        }
+
+        self.pools.get_mut(&pool_id).unwrap().supply += amount;
+
+        Ok(())
+    }
+
+    pub fn burn_units(
+        &mut self,
+        pool_id: PoolId,
+        account_id: AccountId,
+        amount: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        if self.get_account_balance(pool_id, account_id)? < amount {
+            return Err(OperationError::InsufficientPoolUnit);
+        }
+
+        let pool_balances = self.balances.get_mut(&pool_id).unwrap();
+        let pool_balance = pool_balances.get_mut(&account_id).unwrap();
+        *pool_balance -= amount;
+
+        if *pool_balance == 0 {
+            pool_balances.take(&account_id);
+        }
+
+        let detail = self.pools.get_mut(&pool_id).unwrap();
+
+        if detail.supply < amount {
+            return Err(OperationError::BlockchainCorrupted);
+        }
+
+        detail.supply -= amount;
+
+        Ok(())
+    }
+
+    pub fn get_backed_amount(
+        &self,
+        pool_id: PoolId,
+        units: CarbonUnit,
+    ) -> Result<CarbonUnit, OperationError> {
+        match self.pools.get(&pool_id) {
+            None => Err(OperationError::PoolNotFound),
+            Some(detail) if detail.supply == 0 || detail.shortfall == 0 => Ok(units),
+            Some(detail) => Ok((units as u128 * (detail.supply - detail.shortfall) as u128
+                / detail.supply as u128) as CarbonUnit),
+        }
+    }
+
+    pub fn get_required_units(
+        &self,
+        pool_id: PoolId,
+        amount: CarbonUnit,
+    ) -> Result<CarbonUnit, OperationError> {
+        match self.pools.get(&pool_id) {
+            None => Err(OperationError::PoolNotFound),
+            Some(detail) if detail.supply == 0 || detail.shortfall == 0 => Ok(amount),
+            Some(detail) if detail.shortfall >= detail.supply => {
+                Err(OperationError::InsufficientPoolBacking)
+            }
+            Some(detail) => {
+                let backing = (detail.supply - detail.shortfall) as u128;
+                let units = (amount as u128 * detail.supply as u128).div_ceil(backing);
+
+                if units > CarbonUnit::MAX as u128 {
+                    return Err(OperationError::InsufficientPoolUnit);
+                }
+
+                Ok(units as CarbonUnit)
+            }
+        }
+    }
+
+    pub fn write_down(&mut self, pool_id: PoolId, amount: CarbonUnit) -> Option<Detail> {
+        let detail = self.pools.get_mut(&pool_id)?;
+        detail.shortfall = detail.shortfall.saturating_add(amount).min(detail.supply);
+
+        Some(detail.clone())
+    }
+
+    pub fn redeem_units(
+        &mut self,
+        pool_id: PoolId,
+        account_id: AccountId,
+        units: CarbonUnit,
+        amount: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        self.burn_units(pool_id, account_id, units)?;
+        let detail = self.pools.get_mut(&pool_id).unwrap();
+        detail.shortfall = detail
+            .shortfall
+            .saturating_sub(units.saturating_sub(amount))
+            .min(detail.supply);
+
+        Ok(())
+    }
+
+    pub fn transfer_units(
+        &mut self,
+        pool_id: PoolId,
+        account_id: AccountId,
+        target_account_id: AccountId,
+        amount: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        self.burn_units(pool_id, account_id, amount)?;
+        self.mint_units(pool_id, target_account_id, amount)
+    }
+}
@@ -0,0 +1,173 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
+use crate::pagination::{clamp_limit, paginate_indexed, Page};
+use crate::{
+    Decode, Encode, GenericVec, OperationError, ProjectId, SpreadLayout, StorageBox,
+    StorageHashMap, StorageVec, String, TokenId,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, SpreadLayout, PackedLayout, Encode, Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Registry {
+    Verra,
+    GoldStandard,
+    AmericanCarbonRegistry,
+    ClimateActionReserve,
+    Other(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Status {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RegisterParams {
+    pub name: String,
+    pub registry: Registry,
+    pub external_id: String,
+    pub developer: AccountId,
+    pub location: String,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Detail {
+    pub id: ProjectId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub proposer: AccountId,
+    pub name: String,
+    pub registry: Registry,
+    pub external_id: String,
+    pub developer: AccountId,
+    pub location: String,
+    pub status: Status,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Directory {
+    next_project_id: ProjectId,
+    projects: StorageHashMap<ProjectId, Detail>,
+    editions: StorageHashMap<ProjectId, StorageBox<StorageVec<TokenId>>>,
+}
+
+impl Directory {
+    pub fn take_next_project_id(&mut self) -> ProjectId {
+        let next_project_id = self.next_project_id;
+        self.next_project_id += 1;
+
+        next_project_id
+    }
+
+    pub fn register(&mut self, proposer: AccountId, params: RegisterParams) -> Detail {
+        let project_id = self.take_next_project_id();
+        let detail = Detail {
+            id: project_id,
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            proposer,
+            name: params.name,
+            registry: params.registry,
+            external_id: params.external_id,
+            developer: params.developer,
+            location: params.location,
+            status: Status::Pending,
+        };
+        self.projects.insert(project_id, detail.clone());
+        self.editions
+            .insert(project_id, StorageBox::new(StorageVec::new()));
+
+        detail
+    }
+
+    pub fn review(
+        &mut self,
+        project_id: ProjectId,
+        status: Status,
+    ) -> Result<Detail, OperationError> {
+        match self.projects.get_mut(&project_id) {
+            None => Err(OperationError::ProjectNotFound),
+            Some(detail) => {
+                if detail.status != Status::Pending {
+                    return Err(OperationError::ProjectNotPending);
+                }
+
+                detail.status = status;
+
+                Ok(detail.clone())
+            }
+        }
+    }
+
+    pub fn get_by_id(&self, project_id: ProjectId) -> Result<Detail, OperationError> {
+        match self.projects.get(&project_id) {
+            None => Err(OperationError::ProjectNotFound),
+            Some(detail) => Ok(detail.clone()),
+        }
+    }
+
+    pub fn check_is_approved(&self, project_id: ProjectId) -> Result<(), OperationError> {
+        match self.projects.get(&project_id) {
+            None => Err(OperationError::ProjectNotFound),
+            Some(detail) if detail.status != Status::Approved => {
+                Err(OperationError::ProjectNotApproved)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    pub fn link_edition(&mut self, project_id: ProjectId, token_id: TokenId) {
+        if let Some(project_editions) = self.editions.get_mut(&project_id) {
+            project_editions.push(token_id);
+        }
+    }
+
+    pub fn get_edition_ids(
+        &self,
+        project_id: ProjectId,
+    ) -> Result<GenericVec<TokenId>, OperationError> {
+        match self.editions.get(&project_id) {
+            None => Err(OperationError::ProjectNotFound),
+            Some(project_editions) => Ok(project_editions.iter().copied().collect()),
+        }
+    }
+
+    pub fn get_edition_id_page(
+        &self,
+        project_id: ProjectId,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Result<Page<u32, TokenId>, OperationError> {
+        match self.editions.get(&project_id) {
+            None => Err(OperationError::ProjectNotFound),
+            Some(project_editions) => Ok(paginate_indexed(
+                project_editions.len(),
+                start,
+                limit,
+                |index| project_editions.get(index).copied(),
+            )),
+        }
+    }
+
+    pub fn list_page(&self, start: Option<ProjectId>, limit: u32) -> Page<ProjectId, Detail> {
+        let end = self.next_project_id;
+        let start = start.unwrap_or(0).min(end);
+        let limit = clamp_limit(limit);
+        let page_end = start.saturating_add(limit).min(end);
+
+        Page {
+            items: (start..page_end)
+                .filter_map(|project_id| self.projects.get(&project_id).cloned())
+                .collect(),
+            next_cursor: if page_end < end { Some(page_end) } else { None },
+        }
+    }
+}
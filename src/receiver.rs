@@ -0,0 +1,33 @@
+use crate::environment::{AccountId, DefaultEnvironment};
+use crate::{GenericVec, OperationError, TokenEditions};
+use ink_env::call::{build_call, utils::ReturnType, ExecutionInput, Selector};
+
+/// Selector of `on_carbon_received(operator, from, editions, data) -> bool`.
+pub const ON_CARBON_RECEIVED_SELECTOR: [u8; 4] = [0x25, 0xe2, 0x4a, 0x7c];
+
+pub fn notify(
+    operator: AccountId,
+    from: AccountId,
+    to: AccountId,
+    editions: &TokenEditions,
+    data: &GenericVec<u8>,
+) -> Result<(), OperationError> {
+    let result = build_call::<DefaultEnvironment>()
+        .callee(to)
+        .gas_limit(0)
+        .transferred_value(0)
+        .exec_input(
+            ExecutionInput::new(Selector::new(ON_CARBON_RECEIVED_SELECTOR))
+                .push_arg(operator)
+                .push_arg(from)
+                .push_arg(editions)
+                .push_arg(data),
+        )
+        .returns::<ReturnType<bool>>()
+        .fire();
+
+    match result {
+        Ok(true) | Err(ink_env::Error::NotCallable) => Ok(()),
+        _ => Err(OperationError::ReceiverRejected),
+    }
+}
@@ -1,29 +1,25 @@
 use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
+use crate::pagination::{clamp_limit, paginate_indexed, Page};
 use crate::{
     CarbonUnit, Decode, Encode, GenericVec, OperationError, RegistryId, RetirementId,
-    RetirementReports, SpreadLayout, StorageBox, StorageHashMap, StorageVec, TokenBalanceDetail,
-    TokenId,
+    RetirementReports, SerialRange, SpreadLayout, StorageBox, StorageHashMap, StorageVec,
+    TokenBalanceDetail, TokenId,
 };
 use ink_env::{block_number, block_timestamp};
 use ink_storage::traits::PackedLayout;
 
-#[derive(Clone, Debug, Encode, Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub struct Info {
-    pub id: RetirementId,
-    pub amount: CarbonUnit,
-}
-
 #[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct Report {
-    id: RetirementId,
-    block_number: BlockNumber,
-    timestamp: Timestamp,
-    beneficiary: AccountId,
-    token_id: TokenId,
-    amount: CarbonUnit,
-    registry_id: RegistryId,
+    pub id: RetirementId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub beneficiary: AccountId,
+    pub token_id: TokenId,
+    pub amount: CarbonUnit,
+    pub registry_id: RegistryId,
+    pub serials: GenericVec<SerialRange>,
+    pub invalidated_at: Option<BlockNumber>,
 }
 
 #[derive(Debug, Default, SpreadLayout)]
@@ -32,6 +28,7 @@ pub struct Book {
     last_retirement_id: Option<RetirementId>,
     reports: StorageHashMap<RetirementId, Report>,
     account_mapping: StorageHashMap<AccountId, StorageBox<StorageVec<RetirementId>>>,
+    invalidated_editions: StorageHashMap<TokenId, BlockNumber>,
 }
 
 impl Book {
@@ -51,7 +48,7 @@ impl Book {
             return Err(OperationError::RetirementReportNotFound);
         }
 
-        Ok(self.reports.get(&retirement_id).unwrap().clone())
+        Ok(self.flag_report(self.reports.get(&retirement_id).unwrap()))
     }
 
     pub fn get_last_report(&self) -> Result<Report, OperationError> {
@@ -69,18 +66,70 @@ impl Book {
             let account_report_indices = account_report_indices.iter();
 
             for report_id in account_report_indices {
-                reports.push(self.reports.get(report_id).unwrap().clone());
+                reports.push(self.flag_report(self.reports.get(report_id).unwrap()));
             }
         }
 
         reports
     }
 
+    pub fn get_report_page(
+        &self,
+        start: Option<RetirementId>,
+        limit: u32,
+    ) -> Page<RetirementId, Report> {
+        let limit = clamp_limit(limit) as usize;
+        let mut reports = GenericVec::new();
+        let mut retirement_id = start.unwrap_or(0);
+
+        while retirement_id < self.next_retirement_id {
+            if reports.len() == limit {
+                return Page {
+                    items: reports,
+                    next_cursor: Some(retirement_id),
+                };
+            }
+
+            if let Some(report) = self.reports.get(&retirement_id) {
+                reports.push(self.flag_report(report));
+            }
+
+            retirement_id += 1;
+        }
+
+        Page {
+            items: reports,
+            next_cursor: None,
+        }
+    }
+
+    pub fn get_account_report_page(
+        &self,
+        account: AccountId,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Page<u32, Report> {
+        match self.account_mapping.get(&account) {
+            None => Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            },
+            Some(account_report_indices) => {
+                paginate_indexed(account_report_indices.len(), start, limit, |index| {
+                    let report_id = account_report_indices.get(index)?;
+
+                    Some(self.flag_report(self.reports.get(report_id)?))
+                })
+            }
+        }
+    }
+
     pub fn insert_new_report(
         &mut self,
         account: AccountId,
         retirement_detail: &TokenBalanceDetail,
-    ) -> Info {
+        serials: GenericVec<SerialRange>,
+    ) -> Report {
         let next_retirement_id = self.take_next_retirement_id();
         let report = Report {
             id: next_retirement_id,
@@ -90,9 +139,11 @@ impl Book {
             token_id: retirement_detail.detail.id,
             amount: retirement_detail.balance,
             registry_id: retirement_detail.detail.registry_id.clone(),
+            serials,
+            invalidated_at: None,
         };
         self.last_retirement_id = Some(next_retirement_id);
-        self.reports.insert(next_retirement_id, report);
+        self.reports.insert(next_retirement_id, report.clone());
 
         if !self.account_mapping.contains_key(&account) {
             self.account_mapping
@@ -104,9 +155,18 @@ impl Book {
             .unwrap()
             .push(next_retirement_id);
 
-        Info {
-            id: next_retirement_id,
-            amount: retirement_detail.balance,
-        }
+        report
+    }
+
+    pub fn flag_invalidated_edition(&mut self, token_id: TokenId) {
+        self.invalidated_editions
+            .insert(token_id, block_number::<DefaultEnvironment>());
+    }
+
+    fn flag_report(&self, report: &Report) -> Report {
+        let mut report = report.clone();
+        report.invalidated_at = self.invalidated_editions.get(&report.token_id).copied();
+
+        report
     }
 }
@@ -0,0 +1,271 @@
+use crate::{CarbonUnit, Decode, Encode, GenericVec, SerialNumber, SpreadLayout, StorageVec};
+use ink_storage::traits::PackedLayout;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Range {
+    pub start: SerialNumber,
+    pub end: SerialNumber,
+}
+
+impl Range {
+    pub fn new(start: SerialNumber, amount: CarbonUnit) -> Option<Range> {
+        if amount == 0 {
+            return None;
+        }
+
+        start
+            .checked_add(amount - 1)
+            .map(|end| Range { start, end })
+    }
+
+    pub fn len(&self) -> CarbonUnit {
+        (self.end - self.start).saturating_add(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+pub fn count(ranges: &[Range]) -> CarbonUnit {
+    ranges
+        .iter()
+        .fold(0, |total, range| total.saturating_add(range.len()))
+}
+
+pub fn take_lowest(ranges: &mut GenericVec<Range>, amount: CarbonUnit) -> GenericVec<Range> {
+    let mut taken = GenericVec::new();
+    let mut remaining_amount = amount;
+
+    while remaining_amount > 0 && !ranges.is_empty() {
+        let lowest = ranges[0];
+
+        if lowest.len() <= remaining_amount {
+            remaining_amount -= lowest.len();
+            taken.push(ranges.remove(0));
+        } else {
+            taken.push(Range {
+                start: lowest.start,
+                end: lowest.start + remaining_amount - 1,
+            });
+            ranges[0].start += remaining_amount;
+            remaining_amount = 0;
+        }
+    }
+
+    taken
+}
+
+pub fn merge(ranges: &mut GenericVec<Range>, incoming: &[Range]) {
+    ranges.extend_from_slice(incoming);
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: GenericVec<Range> = GenericVec::new();
+
+    for range in ranges.iter() {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(1) => {
+                last.end = last.end.max(range.end)
+            }
+            _ => merged.push(*range),
+        }
+    }
+
+    *ranges = merged;
+}
+
+/// Disjoint ranges sorted by start, so an overlap check only reads the
+/// neighbours found by binary search. Serials are usually issued in
+/// increasing order, which keeps inserts close to the end.
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Index {
+    ranges: StorageVec<Range>,
+}
+
+impl Index {
+    fn find(&self, range: &Range) -> u32 {
+        let mut low = 0;
+        let mut high = self.ranges.len();
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if self.ranges.get(middle).unwrap().end < range.start {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    }
+
+    pub fn overlaps(&self, range: &Range) -> bool {
+        match self.ranges.get(self.find(range)) {
+            None => false,
+            Some(next) => next.overlaps(range),
+        }
+    }
+
+    pub fn insert(&mut self, range: Range) -> bool {
+        if self.overlaps(&range) {
+            return false;
+        }
+
+        let position = self.find(&range);
+        self.ranges.push(range);
+
+        let mut index = self.ranges.len() - 1;
+
+        while index > position {
+            self.ranges.swap(index - 1, index);
+            index -= 1;
+        }
+
+        true
+    }
+
+    pub fn remove(&mut self, range: &Range) {
+        let mut index = self.find(range);
+
+        match self.ranges.get(index) {
+            Some(found) if found == range => (),
+            _ => return,
+        }
+
+        while index + 1 < self.ranges.len() {
+            self.ranges.swap(index, index + 1);
+            index += 1;
+        }
+
+        self.ranges.pop_drop();
+    }
+
+    pub fn len(&self) -> u32 {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: SerialNumber, end: SerialNumber) -> Range {
+        Range { start, end }
+    }
+
+    #[test]
+    fn merge_joins_adjacent_ranges() {
+        let mut ranges = vec![range(1, 10)];
+        merge(&mut ranges, &[range(11, 20), range(30, 40)]);
+
+        assert_eq!(ranges, vec![range(1, 20), range(30, 40)]);
+    }
+
+    #[test]
+    fn merge_joins_overlapping_ranges() {
+        let mut ranges = vec![range(5, 15), range(1, 3)];
+        merge(&mut ranges, &[range(10, 20), range(2, 4)]);
+
+        assert_eq!(ranges, vec![range(1, 20)]);
+    }
+
+    #[test]
+    fn merge_keeps_contained_ranges_whole() {
+        let mut ranges = vec![range(1, 100)];
+        merge(&mut ranges, &[range(10, 20)]);
+
+        assert_eq!(ranges, vec![range(1, 100)]);
+    }
+
+    #[test]
+    fn take_lowest_splits_partial_range() {
+        let mut ranges = vec![range(1, 10), range(20, 29)];
+        let taken = take_lowest(&mut ranges, 13);
+
+        assert_eq!(taken, vec![range(1, 10), range(20, 22)]);
+        assert_eq!(ranges, vec![range(23, 29)]);
+    }
+
+    #[test]
+    fn take_lowest_stops_when_ranges_run_out() {
+        let mut ranges = vec![range(1, 5)];
+        let taken = take_lowest(&mut ranges, 8);
+
+        assert_eq!(taken, vec![range(1, 5)]);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn ranges_at_serial_number_max() {
+        assert_eq!(
+            Range::new(SerialNumber::MAX, 1),
+            Some(range(SerialNumber::MAX, SerialNumber::MAX))
+        );
+        assert_eq!(Range::new(SerialNumber::MAX, 2), None);
+
+        let mut ranges = vec![range(0, SerialNumber::MAX - 1)];
+        merge(&mut ranges, &[range(SerialNumber::MAX, SerialNumber::MAX)]);
+
+        assert_eq!(ranges, vec![range(0, SerialNumber::MAX)]);
+        assert_eq!(count(&ranges), CarbonUnit::MAX);
+
+        let mut ranges = vec![range(SerialNumber::MAX - 2, SerialNumber::MAX)];
+        let taken = take_lowest(&mut ranges, 2);
+
+        assert_eq!(
+            taken,
+            vec![range(SerialNumber::MAX - 2, SerialNumber::MAX - 1)]
+        );
+        assert_eq!(ranges, vec![range(SerialNumber::MAX, SerialNumber::MAX)]);
+    }
+
+    #[test]
+    fn index_rejects_overlaps_with_neighbours() {
+        let mut index = Index::default();
+
+        assert!(index.insert(range(20, 29)));
+        assert!(index.insert(range(1, 10)));
+        assert!(index.insert(range(40, 49)));
+        assert!(!index.insert(range(10, 12)));
+        assert!(!index.insert(range(25, 45)));
+        assert!(!index.insert(range(0, SerialNumber::MAX)));
+        assert!(index.insert(range(11, 19)));
+        assert!(index.insert(range(50, SerialNumber::MAX)));
+        assert_eq!(index.len(), 5);
+        assert!(index.overlaps(&range(45, 45)));
+        assert!(!index.overlaps(&range(30, 39)));
+    }
+
+    #[test]
+    fn index_remove_frees_the_range() {
+        let mut index = Index::default();
+        index.insert(range(1, 10));
+        index.insert(range(20, 29));
+        index.insert(range(40, 49));
+
+        index.remove(&range(20, 25));
+        assert_eq!(index.len(), 3);
+
+        index.remove(&range(20, 29));
+        assert_eq!(index.len(), 2);
+        assert!(index.insert(range(15, 35)));
+        assert!(!index.insert(range(9, 9)));
+        assert!(!index.insert(range(49, 50)));
+    }
+
+    #[test]
+    fn overlap_is_inclusive() {
+        assert!(range(1, 10).overlaps(&range(10, 20)));
+        assert!(!range(1, 10).overlaps(&range(11, 20)));
+    }
+}
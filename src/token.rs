@@ -1,28 +1,108 @@
-use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Timestamp};
-use crate::utils::get_blackhole_address;
+use crate::attribute::{Attributes, Filter as AttributeFilter};
+use crate::checkpoint::{History, Snapshot};
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Hash, Timestamp};
+use crate::pagination::{clamp_limit, paginate_indexed, KeyIndex, Page, MAX_PAGE_SCAN};
+use crate::project::Registry;
+use crate::serial::{self, Range as SerialRange};
+use crate::utils::{get_blackhole_address, get_buffer_address};
 use crate::{
-    CarbonUnit, Decode, Encode, GenericVec, MintBeneficiaryAccount, OperationError, RegistryId,
-    SpreadLayout, StorageBox, StorageHashMap, StorageVec, TokenEditions, TokenId, Year,
+    CarbonUnit, Decode, Encode, GenericVec, MintBeneficiaryAccount, OperationError, ProjectId,
+    RegistryId, SerialNumber, SnapshotId, SpreadLayout, StorageBox, StorageHashMap, StorageVec,
+    String, TokenEditions, TokenId, TokenTransferBatch, Year,
 };
 use ink_env::{block_number, block_timestamp};
+use ink_prelude::collections::BTreeMap;
 use ink_storage::traits::PackedLayout;
 
+pub const MAX_INVALIDATED_HOLDERS_PER_CALL: u32 = 32;
+
 #[derive(Clone, Debug, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct MintRequestParams {
+    pub project_id: ProjectId,
     pub registry_id: RegistryId,
     pub verified_carbon_unit: CarbonUnit,
+    pub serial_start: SerialNumber,
     pub issuance_year: Year,
     pub beneficiary: MintBeneficiaryAccount,
+    pub attributes: Attributes,
 }
 
 #[derive(Clone, Debug, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct SupplementRequestParams {
+    pub token_id: TokenId,
+    pub serial_suffix: String,
+    pub verified_carbon_unit: CarbonUnit,
+    pub serial_start: SerialNumber,
+    pub beneficiary: MintBeneficiaryAccount,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Issuance {
+    pub token_id: TokenId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub minter: AccountId,
+    pub amount: CarbonUnit,
+    pub serials: SerialRange,
+    pub serial_suffix: Option<String>,
+    pub beneficiary: MintBeneficiaryAccount,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct LockedSupplement {
+    pub issuance: Issuance,
+    pub buffer_amount: CarbonUnit,
+    pub activation_block: BlockNumber,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct TokenEdition {
     pub id: TokenId,
     pub amount: CarbonUnit,
 }
 
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum AllocationStrategy {
+    OldestMintFirst,
+    NewestMintFirst,
+    LargestBalanceFirst,
+    SmallestBalanceFirst,
+    PreferredMinters(GenericVec<AccountId>),
+}
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TransferMemo {
+    pub reference: Option<String>,
+    pub reference_hash: Option<Hash>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Status {
+    Active,
+    Frozen,
+    Invalidated,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Invalidation {
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub reason: String,
+    pub evidence_hash: Hash,
+    pub cancelled: CarbonUnit,
+    pub affected_holders: u32,
+    pub remaining_holders: u32,
+}
+
 #[derive(Clone, Debug, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct TokenBalanceDetail {
@@ -30,17 +110,30 @@ pub struct TokenBalanceDetail {
     pub detail: Detail,
 }
 
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TokenHolder {
+    pub account_id: AccountId,
+    pub balance: CarbonUnit,
+}
+
 #[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct Detail {
     pub id: TokenId,
     pub block_number: BlockNumber,
     pub timestamp: Timestamp,
+    pub minted_block_number: Option<BlockNumber>,
     pub minter: AccountId,
     pub supply: CarbonUnit,
     pub retired: CarbonUnit,
     pub year: Year,
     pub registry_id: RegistryId,
+    pub project_id: ProjectId,
+    pub attributes: Attributes,
+    pub serials: SerialRange,
+    pub status: Status,
+    pub invalidation: Option<Invalidation>,
 }
 
 #[derive(Debug, Default, SpreadLayout)]
@@ -50,7 +143,29 @@ pub struct Tracker {
     minted_editions: StorageHashMap<TokenId, Detail>,
     pending_mint_editions: StorageHashMap<RegistryId, (Detail, MintBeneficiaryAccount)>,
     balances: StorageHashMap<AccountId, StorageBox<StorageHashMap<TokenId, CarbonUnit>>>,
+    balance_ids: StorageHashMap<AccountId, StorageBox<KeyIndex<TokenId>>>,
     year_mapping: StorageHashMap<Year, StorageBox<StorageVec<TokenId>>>,
+    holders: StorageHashMap<TokenId, StorageBox<StorageHashMap<AccountId, CarbonUnit>>>,
+    holder_ids: StorageHashMap<TokenId, StorageBox<KeyIndex<AccountId>>>,
+    cancelled_holdings: StorageHashMap<TokenId, StorageBox<StorageVec<TokenHolder>>>,
+    serials: StorageHashMap<(AccountId, TokenId), GenericVec<SerialRange>>,
+    history: History,
+    pending_mint_ttl: Option<BlockNumber>,
+    pending_activations: StorageHashMap<TokenId, BlockNumber>,
+    pending_supplements: StorageHashMap<RegistryId, Issuance>,
+    locked_supplements: StorageHashMap<RegistryId, LockedSupplement>,
+    issuances: StorageHashMap<TokenId, StorageBox<StorageVec<Issuance>>>,
+    registry_ids: StorageHashMap<RegistryId, TokenId>,
+    edition_registries: StorageHashMap<TokenId, Registry>,
+    registry_serials: StorageHashMap<Registry, StorageBox<serial::Index>>,
+}
+
+pub fn get_supplement_registry_id(registry_id: &RegistryId, serial_suffix: &str) -> RegistryId {
+    let mut supplement_registry_id = registry_id.clone();
+    supplement_registry_id.push('/');
+    supplement_registry_id.push_str(serial_suffix);
+
+    supplement_registry_id
 }
 
 impl Tracker {
@@ -61,75 +176,628 @@ impl Tracker {
         next_token_id
     }
 
+    pub fn check_is_unique(
+        &self,
+        registry_id: &RegistryId,
+        registry: &Registry,
+        serials: &SerialRange,
+    ) -> Result<(), OperationError> {
+        if self.pending_mint_editions.contains_key(registry_id)
+            || self.pending_supplements.contains_key(registry_id)
+        {
+            return Err(OperationError::TokenMintRequestAlreadyPending);
+        }
+
+        if self.registry_ids.contains_key(registry_id) {
+            return Err(OperationError::TokenAlreadyMinted);
+        }
+
+        if let Some(registry_serials) = self.registry_serials.get(registry) {
+            if registry_serials.overlaps(serials) {
+                return Err(OperationError::InvalidSerialRange);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn reserve_registry_id(
+        &mut self,
+        registry_id: RegistryId,
+        token_id: TokenId,
+        registry: Registry,
+        serials: SerialRange,
+    ) {
+        self.registry_ids.insert(registry_id, token_id);
+
+        if !self.registry_serials.contains_key(&registry) {
+            self.registry_serials
+                .insert(registry.clone(), StorageBox::new(serial::Index::default()));
+        }
+
+        self.registry_serials
+            .get_mut(&registry)
+            .unwrap()
+            .insert(serials);
+    }
+
+    fn release_registry_id(
+        &mut self,
+        registry_id: &RegistryId,
+        token_id: TokenId,
+        serials: &SerialRange,
+    ) {
+        self.registry_ids.take(registry_id);
+
+        if let Some(registry) = self.edition_registries.get(&token_id) {
+            if let Some(registry_serials) = self.registry_serials.get_mut(registry) {
+                registry_serials.remove(serials);
+            }
+        }
+    }
+
     pub fn insert_pending_mint(
         &mut self,
         minter: AccountId,
+        registry: Registry,
         params: MintRequestParams,
-    ) -> Result<(), OperationError> {
-        if self.pending_mint_editions.contains_key(&params.registry_id) {
-            return Err(OperationError::TokenMintRequestAlreadyPending);
-        }
+    ) -> Result<Detail, OperationError> {
+        params.attributes.validate()?;
+        let serials = match SerialRange::new(params.serial_start, params.verified_carbon_unit) {
+            None => return Err(OperationError::InvalidSerialRange),
+            Some(serials) => serials,
+        };
+        self.check_is_unique(&params.registry_id, &registry, &serials)?;
 
         let detail = Detail {
             id: self.take_next_token_id(),
             registry_id: params.registry_id.clone(),
+            project_id: params.project_id,
+            attributes: params.attributes,
+            serials,
+            status: Status::Active,
+            invalidation: None,
             supply: params.verified_carbon_unit,
             retired: 0,
             year: params.issuance_year,
             minter,
             block_number: block_number::<DefaultEnvironment>(),
             timestamp: block_timestamp::<DefaultEnvironment>(),
+            minted_block_number: None,
         };
+        self.reserve_registry_id(
+            params.registry_id.clone(),
+            detail.id,
+            registry.clone(),
+            serials,
+        );
+        self.edition_registries.insert(detail.id, registry);
         self.pending_mint_editions
-            .insert(params.registry_id, (detail, params.beneficiary));
+            .insert(params.registry_id, (detail.clone(), params.beneficiary));
 
-        Ok(())
+        Ok(detail)
+    }
+
+    pub fn get_pending_mint(
+        &self,
+        registry_id: &RegistryId,
+    ) -> Result<(Detail, MintBeneficiaryAccount), OperationError> {
+        match self.pending_mint_editions.get(registry_id) {
+            None => Err(OperationError::TokenMintRequestNotFound),
+            Some(pending_mint) => Ok(pending_mint.clone()),
+        }
+    }
+
+    pub fn get_pending_mint_ttl(&self) -> Option<BlockNumber> {
+        self.pending_mint_ttl
+    }
+
+    pub fn set_pending_mint_ttl(&mut self, pending_mint_ttl: Option<BlockNumber>) {
+        self.pending_mint_ttl = pending_mint_ttl;
+    }
+
+    pub fn is_pending_mint_expired(&self, detail: &Detail) -> bool {
+        match self.pending_mint_ttl {
+            None => false,
+            Some(pending_mint_ttl) => {
+                block_number::<DefaultEnvironment>()
+                    >= detail.block_number.saturating_add(pending_mint_ttl)
+            }
+        }
+    }
+
+    pub fn withdraw_pending_mint(
+        &mut self,
+        minter: AccountId,
+        registry_id: &RegistryId,
+    ) -> Result<(Detail, MintBeneficiaryAccount), OperationError> {
+        let (detail, _) = self.get_pending_mint(registry_id)?;
+
+        if detail.minter != minter {
+            return Err(OperationError::Unauthorized);
+        }
+
+        self.deny_pending_mint(registry_id)
+    }
+
+    pub fn expire_pending_mint(
+        &mut self,
+        registry_id: &RegistryId,
+    ) -> Result<(Detail, MintBeneficiaryAccount), OperationError> {
+        let (detail, _) = self.get_pending_mint(registry_id)?;
+
+        if !self.is_pending_mint_expired(&detail) {
+            return Err(OperationError::TokenMintRequestNotExpired);
+        }
+
+        self.deny_pending_mint(registry_id)
     }
 
     pub fn deny_pending_mint(
         &mut self,
         registry_id: &RegistryId,
-    ) -> Result<AccountId, OperationError> {
+    ) -> Result<(Detail, MintBeneficiaryAccount), OperationError> {
         match self.pending_mint_editions.take(registry_id) {
             None => Err(OperationError::TokenMintRequestNotFound),
-            Some((detail, _)) => Ok(detail.minter),
+            Some(pending_mint) => {
+                let detail = &pending_mint.0;
+                self.release_registry_id(registry_id, detail.id, &detail.serials);
+                self.edition_registries.take(&detail.id);
+
+                Ok(pending_mint)
+            }
         }
     }
 
     pub fn approve_pending_mint(
         &mut self,
         registry_id: &RegistryId,
-    ) -> Result<(AccountId, MintBeneficiaryAccount, TokenId, CarbonUnit), OperationError> {
+        buffer_amount: CarbonUnit,
+    ) -> Result<(Detail, MintBeneficiaryAccount), OperationError> {
         match self.pending_mint_editions.take(registry_id) {
             None => Err(OperationError::TokenMintRequestNotFound),
-            Some((detail, target_account_id)) => {
-                let minter = detail.minter;
+            Some((mut detail, target_account_id)) => {
+                detail.minted_block_number = Some(block_number::<DefaultEnvironment>());
                 let token_id = detail.id;
                 let token_year = detail.year;
                 let token_supply = detail.supply;
                 self.minted_editions.insert(detail.id, detail.clone());
 
-                if !self.balances.contains_key(&target_account_id) {
-                    self.balances
-                        .insert(target_account_id, StorageBox::new(StorageHashMap::new()));
-                }
-
                 if !self.year_mapping.contains_key(&token_year) {
                     self.year_mapping
                         .insert(token_year, StorageBox::new(StorageVec::new()));
                 }
 
-                let target_account_balance = self.balances.get_mut(&target_account_id).unwrap();
-                target_account_balance.insert(detail.id, detail.supply);
+                self.history.record_supply(token_id, token_supply);
+                self.credit_issuance(token_id, target_account_id, detail.serials, buffer_amount);
+                self.record_issuance(Issuance {
+                    token_id,
+                    block_number: block_number::<DefaultEnvironment>(),
+                    timestamp: block_timestamp::<DefaultEnvironment>(),
+                    minter: detail.minter,
+                    amount: token_supply,
+                    serials: detail.serials,
+                    serial_suffix: None,
+                    beneficiary: target_account_id,
+                });
+
                 let year_mapping = self.year_mapping.get_mut(&token_year).unwrap();
                 year_mapping.push(token_id);
                 self.last_minted_token_id = Some(token_id);
 
-                Ok((minter, target_account_id, token_id, token_supply))
+                Ok((detail, target_account_id))
+            }
+        }
+    }
+
+    pub fn schedule_activation(&mut self, token_id: TokenId, activation_block: BlockNumber) {
+        self.pending_activations.insert(token_id, activation_block);
+    }
+
+    pub fn get_activation_block(&self, token_id: TokenId) -> Option<BlockNumber> {
+        self.pending_activations.get(&token_id).copied()
+    }
+
+    pub fn is_active(&self, token_id: TokenId) -> bool {
+        self.check_is_active(token_id).is_ok()
+    }
+
+    pub fn check_is_active(&self, token_id: TokenId) -> Result<(), OperationError> {
+        if self.pending_activations.contains_key(&token_id) {
+            return Err(OperationError::TokenNotActive);
+        }
+
+        match self.minted_editions.get(&token_id) {
+            None => Err(OperationError::TokenNotFound),
+            Some(detail) => match detail.status {
+                Status::Active => Ok(()),
+                Status::Frozen => Err(OperationError::TokenFrozen),
+                Status::Invalidated => Err(OperationError::TokenInvalidated),
+            },
+        }
+    }
+
+    pub fn set_status(&mut self, token_id: TokenId, status: Status) -> Result<(), OperationError> {
+        match self.minted_editions.get_mut(&token_id) {
+            None => Err(OperationError::TokenNotFound),
+            Some(detail) => {
+                if detail.status == Status::Invalidated || status == Status::Invalidated {
+                    return Err(OperationError::TokenInvalidated);
+                }
+
+                detail.status = status;
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn invalidate(
+        &mut self,
+        token_id: TokenId,
+        reason: String,
+        evidence_hash: Hash,
+    ) -> Result<Detail, OperationError> {
+        let remaining_holders = self.get_holder_count(token_id)?;
+        let detail = self.minted_editions.get_mut(&token_id).unwrap();
+
+        if detail.status == Status::Invalidated {
+            return Err(OperationError::TokenInvalidated);
+        }
+
+        detail.invalidation = Some(Invalidation {
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            reason,
+            evidence_hash,
+            cancelled: detail.supply,
+            affected_holders: 0,
+            remaining_holders,
+        });
+        detail.status = Status::Invalidated;
+        let detail = detail.clone();
+        self.pending_activations.take(&token_id);
+
+        Ok(detail)
+    }
+
+    pub fn write_down_invalidated_holders(
+        &mut self,
+        token_id: TokenId,
+    ) -> Result<(Detail, GenericVec<TokenHolder>), OperationError> {
+        let invalidation = match self.get_edition_details(token_id)?.invalidation {
+            None => return Err(OperationError::TokenNotInvalidated),
+            Some(invalidation) => invalidation,
+        };
+
+        if invalidation.remaining_holders == 0 {
+            return Err(OperationError::TokenInvalidationComplete);
+        }
+
+        let mut written_down = GenericVec::new();
+
+        while (written_down.len() as u32) < MAX_INVALIDATED_HOLDERS_PER_CALL {
+            let account_id = match self.holder_ids.get(&token_id) {
+                None => break,
+                Some(edition_holder_ids) if edition_holder_ids.is_empty() => break,
+                Some(edition_holder_ids) => *edition_holder_ids
+                    .get(edition_holder_ids.len() - 1)
+                    .unwrap(),
+            };
+            let balance = self.get_account_balance_by_id(account_id, token_id)?;
+            self.debit_balance(account_id, token_id, balance)?;
+            written_down.push(TokenHolder {
+                account_id,
+                balance,
+            });
+        }
+
+        if !self.cancelled_holdings.contains_key(&token_id) {
+            self.cancelled_holdings
+                .insert(token_id, StorageBox::new(StorageVec::new()));
+        }
+
+        let edition_cancelled_holdings = self.cancelled_holdings.get_mut(&token_id).unwrap();
+
+        for holder in &written_down {
+            edition_cancelled_holdings.push(holder.clone());
+        }
+
+        let remaining_holders = self.get_holder_count(token_id)?;
+        let detail = self.minted_editions.get_mut(&token_id).unwrap();
+        let written_down_amount = written_down.iter().fold(0 as CarbonUnit, |total, holder| {
+            total.saturating_add(holder.balance)
+        });
+        detail.supply = detail.supply.saturating_sub(written_down_amount);
+
+        if let Some(invalidation) = detail.invalidation.as_mut() {
+            invalidation.affected_holders += written_down.len() as u32;
+            invalidation.remaining_holders = remaining_holders;
+        }
+
+        let detail = detail.clone();
+        self.history.record_supply(token_id, detail.supply);
+
+        Ok((detail, written_down))
+    }
+
+    pub fn get_cancelled_holdings(
+        &self,
+        token_id: TokenId,
+        limit: u32,
+    ) -> Result<GenericVec<TokenHolder>, OperationError> {
+        match self.cancelled_holdings.get(&token_id) {
+            None => Ok(GenericVec::new()),
+            Some(edition_cancelled_holdings) if edition_cancelled_holdings.len() > limit => {
+                Err(OperationError::CustodianSlashPayoutsExceeded)
+            }
+            Some(edition_cancelled_holdings) => {
+                Ok(edition_cancelled_holdings.iter().cloned().collect())
+            }
+        }
+    }
+
+    pub fn check_is_pending_activation(&self, token_id: TokenId) -> Result<(), OperationError> {
+        match self.pending_activations.get(&token_id) {
+            None => Err(OperationError::TokenNotPendingActivation),
+            Some(activation_block) if block_number::<DefaultEnvironment>() >= *activation_block => {
+                Err(OperationError::ChallengePeriodElapsed)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    pub fn activate(&mut self, token_id: TokenId) -> Result<(), OperationError> {
+        match self.pending_activations.get(&token_id) {
+            None => Err(OperationError::TokenNotPendingActivation),
+            Some(activation_block) if block_number::<DefaultEnvironment>() < *activation_block => {
+                Err(OperationError::TokenActivationPending)
+            }
+            Some(_) => {
+                self.pending_activations.take(&token_id);
+
+                Ok(())
             }
         }
     }
 
+    pub fn insert_pending_supplement(
+        &mut self,
+        minter: AccountId,
+        params: SupplementRequestParams,
+    ) -> Result<(RegistryId, Issuance), OperationError> {
+        let detail = self.get_edition_details(params.token_id)?;
+
+        if detail.minter != minter {
+            return Err(OperationError::Unauthorized);
+        }
+
+        self.check_is_active(params.token_id)?;
+
+        if params.serial_suffix.is_empty() {
+            return Err(OperationError::InvalidSerialRange);
+        }
+
+        let serials = match SerialRange::new(params.serial_start, params.verified_carbon_unit) {
+            None => return Err(OperationError::InvalidSerialRange),
+            Some(serials) => serials,
+        };
+        let registry_id = get_supplement_registry_id(&detail.registry_id, &params.serial_suffix);
+        let registry = match self.edition_registries.get(&params.token_id) {
+            None => return Err(OperationError::BlockchainCorrupted),
+            Some(registry) => registry.clone(),
+        };
+        self.check_is_unique(&registry_id, &registry, &serials)?;
+
+        let issuance = Issuance {
+            token_id: params.token_id,
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            minter,
+            amount: params.verified_carbon_unit,
+            serials,
+            serial_suffix: Some(params.serial_suffix),
+            beneficiary: params.beneficiary,
+        };
+        self.reserve_registry_id(registry_id.clone(), params.token_id, registry, serials);
+        self.pending_supplements
+            .insert(registry_id.clone(), issuance.clone());
+
+        Ok((registry_id, issuance))
+    }
+
+    pub fn get_pending_supplement(
+        &self,
+        registry_id: &RegistryId,
+    ) -> Result<Issuance, OperationError> {
+        match self.pending_supplements.get(registry_id) {
+            None => Err(OperationError::TokenMintRequestNotFound),
+            Some(issuance) => Ok(issuance.clone()),
+        }
+    }
+
+    pub fn deny_pending_supplement(
+        &mut self,
+        registry_id: &RegistryId,
+    ) -> Result<Issuance, OperationError> {
+        match self.pending_supplements.take(registry_id) {
+            None => Err(OperationError::TokenMintRequestNotFound),
+            Some(issuance) => {
+                self.release_registry_id(registry_id, issuance.token_id, &issuance.serials);
+
+                Ok(issuance)
+            }
+        }
+    }
+
+    pub fn is_pending_supplement_expired(&self, issuance: &Issuance) -> bool {
+        match self.pending_mint_ttl {
+            None => false,
+            Some(pending_mint_ttl) => {
+                block_number::<DefaultEnvironment>()
+                    >= issuance.block_number.saturating_add(pending_mint_ttl)
+            }
+        }
+    }
+
+    pub fn expire_pending_supplement(
+        &mut self,
+        registry_id: &RegistryId,
+    ) -> Result<Issuance, OperationError> {
+        if !self.is_pending_supplement_expired(&self.get_pending_supplement(registry_id)?) {
+            return Err(OperationError::TokenMintRequestNotExpired);
+        }
+
+        self.deny_pending_supplement(registry_id)
+    }
+
+    pub fn withdraw_pending_supplement(
+        &mut self,
+        minter: AccountId,
+        registry_id: &RegistryId,
+    ) -> Result<Issuance, OperationError> {
+        if self.get_pending_supplement(registry_id)?.minter != minter {
+            return Err(OperationError::Unauthorized);
+        }
+
+        self.deny_pending_supplement(registry_id)
+    }
+
+    pub fn approve_pending_supplement(
+        &mut self,
+        registry_id: &RegistryId,
+        buffer_amount: CarbonUnit,
+        activation_block: Option<BlockNumber>,
+    ) -> Result<(Detail, Issuance), OperationError> {
+        let pending_issuance = self.get_pending_supplement(registry_id)?;
+
+        if self.is_pending_supplement_expired(&pending_issuance) {
+            return Err(OperationError::TokenMintRequestExpired);
+        }
+
+        self.check_is_active(pending_issuance.token_id)?;
+        let mut issuance = self.pending_supplements.take(registry_id).unwrap();
+        let token_id = issuance.token_id;
+        issuance.block_number = block_number::<DefaultEnvironment>();
+        issuance.timestamp = block_timestamp::<DefaultEnvironment>();
+
+        match activation_block {
+            None => Ok((self.issue_supplement(&issuance, buffer_amount), issuance)),
+            Some(activation_block) => {
+                self.locked_supplements.insert(
+                    registry_id.clone(),
+                    LockedSupplement {
+                        issuance: issuance.clone(),
+                        buffer_amount,
+                        activation_block,
+                    },
+                );
+
+                Ok((self.get_edition_details(token_id)?, issuance))
+            }
+        }
+    }
+
+    fn issue_supplement(&mut self, issuance: &Issuance, buffer_amount: CarbonUnit) -> Detail {
+        let token_id = issuance.token_id;
+        let edition_detail = self.minted_editions.get_mut(&token_id).unwrap();
+        edition_detail.supply += issuance.amount;
+        let edition_supply = edition_detail.supply;
+        let detail = edition_detail.clone();
+        self.history.record_supply(token_id, edition_supply);
+        self.credit_issuance(
+            token_id,
+            issuance.beneficiary,
+            issuance.serials,
+            buffer_amount,
+        );
+        self.record_issuance(issuance.clone());
+
+        detail
+    }
+
+    pub fn get_locked_supplement(
+        &self,
+        registry_id: &RegistryId,
+    ) -> Result<LockedSupplement, OperationError> {
+        match self.locked_supplements.get(registry_id) {
+            None => Err(OperationError::TokenNotPendingActivation),
+            Some(locked_supplement) => Ok(locked_supplement.clone()),
+        }
+    }
+
+    pub fn check_is_supplement_pending_activation(
+        &self,
+        registry_id: &RegistryId,
+    ) -> Result<(), OperationError> {
+        if block_number::<DefaultEnvironment>()
+            >= self.get_locked_supplement(registry_id)?.activation_block
+        {
+            return Err(OperationError::ChallengePeriodElapsed);
+        }
+
+        Ok(())
+    }
+
+    pub fn activate_supplement(
+        &mut self,
+        registry_id: &RegistryId,
+    ) -> Result<(Detail, LockedSupplement), OperationError> {
+        let locked_supplement = self.get_locked_supplement(registry_id)?;
+
+        if block_number::<DefaultEnvironment>() < locked_supplement.activation_block {
+            return Err(OperationError::TokenActivationPending);
+        }
+
+        if self
+            .get_edition_details(locked_supplement.issuance.token_id)?
+            .status
+            == Status::Invalidated
+        {
+            return Err(OperationError::TokenInvalidated);
+        }
+
+        self.locked_supplements.take(registry_id);
+        let detail =
+            self.issue_supplement(&locked_supplement.issuance, locked_supplement.buffer_amount);
+
+        Ok((detail, locked_supplement))
+    }
+
+    pub fn cancel_locked_supplement(
+        &mut self,
+        registry_id: &RegistryId,
+    ) -> Result<LockedSupplement, OperationError> {
+        match self.locked_supplements.take(registry_id) {
+            None => Err(OperationError::TokenNotPendingActivation),
+            Some(locked_supplement) => Ok(locked_supplement),
+        }
+    }
+
+    pub fn get_issuance_page(
+        &self,
+        token_id: TokenId,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Result<Page<u32, Issuance>, OperationError> {
+        if !self.minted_editions.contains_key(&token_id) {
+            return Err(OperationError::TokenNotFound);
+        }
+
+        match self.issuances.get(&token_id) {
+            None => Ok(Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            }),
+            Some(edition_issuances) => Ok(paginate_indexed(
+                edition_issuances.len(),
+                start,
+                limit,
+                |index| edition_issuances.get(index).cloned(),
+            )),
+        }
+    }
+
     pub fn get_edition_details(&self, id: TokenId) -> Result<Detail, OperationError> {
         match self.minted_editions.get(&id) {
             None => Err(OperationError::TokenNotFound),
@@ -156,6 +824,88 @@ impl Tracker {
         }
     }
 
+    pub fn get_minted_edition_page(
+        &self,
+        start: Option<TokenId>,
+        limit: u32,
+    ) -> Page<TokenId, Detail> {
+        let limit = clamp_limit(limit) as usize;
+        let mut editions = GenericVec::new();
+        let mut token_id = start.unwrap_or(0);
+        let scan_end = token_id.saturating_add(MAX_PAGE_SCAN);
+
+        while token_id < self.next_token_id {
+            if editions.len() == limit || token_id == scan_end {
+                return Page {
+                    items: editions,
+                    next_cursor: Some(token_id),
+                };
+            }
+
+            if let Some(detail) = self.minted_editions.get(&token_id) {
+                editions.push(detail.clone());
+            }
+
+            token_id += 1;
+        }
+
+        Page {
+            items: editions,
+            next_cursor: None,
+        }
+    }
+
+    pub fn get_minted_edition_page_by_year(
+        &self,
+        year: Year,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Result<Page<u32, Detail>, OperationError> {
+        match self.year_mapping.get(&year) {
+            None => Err(OperationError::TokenNotFound),
+            Some(token_indices) => Ok(paginate_indexed(
+                token_indices.len(),
+                start,
+                limit,
+                |index| self.minted_editions.get(token_indices.get(index)?).cloned(),
+            )),
+        }
+    }
+
+    pub fn get_minted_edition_page_by_attributes(
+        &self,
+        filter: &AttributeFilter,
+        start: Option<TokenId>,
+        limit: u32,
+    ) -> Page<TokenId, Detail> {
+        let limit = clamp_limit(limit) as usize;
+        let mut editions = GenericVec::new();
+        let mut token_id = start.unwrap_or(0);
+        let scan_end = token_id.saturating_add(MAX_PAGE_SCAN);
+
+        while token_id < self.next_token_id {
+            if editions.len() == limit || token_id == scan_end {
+                return Page {
+                    items: editions,
+                    next_cursor: Some(token_id),
+                };
+            }
+
+            if let Some(detail) = self.minted_editions.get(&token_id) {
+                if filter.matches(&detail.attributes) {
+                    editions.push(detail.clone());
+                }
+            }
+
+            token_id += 1;
+        }
+
+        Page {
+            items: editions,
+            next_cursor: None,
+        }
+    }
+
     pub fn get_total_supply(&self) -> CarbonUnit {
         let mut total_supply = 0;
 
@@ -186,9 +936,21 @@ impl Tracker {
                     }
                 }
 
-                Ok(year_supply)
+                Ok(year_supply)
+            }
+        }
+    }
+
+    pub fn get_supply_by_attributes(&self, filter: &AttributeFilter) -> CarbonUnit {
+        let mut filtered_supply = 0;
+
+        for detail in self.minted_editions.values() {
+            if filter.matches(&detail.attributes) {
+                filtered_supply += detail.supply;
             }
         }
+
+        filtered_supply
     }
 
     pub fn get_total_retired(&self) -> CarbonUnit {
@@ -226,6 +988,18 @@ impl Tracker {
         }
     }
 
+    pub fn get_retired_by_attributes(&self, filter: &AttributeFilter) -> CarbonUnit {
+        let mut filtered_retired_supply = 0;
+
+        for detail in self.minted_editions.values() {
+            if filter.matches(&detail.attributes) {
+                filtered_retired_supply += detail.retired;
+            }
+        }
+
+        filtered_retired_supply
+    }
+
     pub fn get_account_balances(&self, account_id: AccountId) -> GenericVec<TokenBalanceDetail> {
         match self.balances.get(&account_id) {
             None => GenericVec::new(),
@@ -245,6 +1019,31 @@ impl Tracker {
         }
     }
 
+    pub fn get_account_balance_page(
+        &self,
+        account_id: AccountId,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Page<u32, TokenBalanceDetail> {
+        match (
+            self.balances.get(&account_id),
+            self.balance_ids.get(&account_id),
+        ) {
+            (Some(account_balances), Some(account_token_ids)) => {
+                account_token_ids.get_page(start, limit, |token_id| {
+                    Some(TokenBalanceDetail {
+                        detail: self.minted_editions.get(token_id)?.clone(),
+                        balance: *account_balances.get(token_id)?,
+                    })
+                })
+            }
+            _ => Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            },
+        }
+    }
+
     pub fn get_account_total_balance(&self, account_id: AccountId) -> CarbonUnit {
         match self.balances.get(&account_id) {
             None => 0,
@@ -310,6 +1109,76 @@ impl Tracker {
         }
     }
 
+    pub fn get_holder_page(
+        &self,
+        token_id: TokenId,
+        start: Option<u32>,
+        limit: u32,
+    ) -> Result<Page<u32, TokenHolder>, OperationError> {
+        if !self.minted_editions.contains_key(&token_id) {
+            return Err(OperationError::TokenNotFound);
+        }
+
+        match (self.holders.get(&token_id), self.holder_ids.get(&token_id)) {
+            (Some(edition_holders), Some(edition_holder_ids)) => {
+                Ok(edition_holder_ids.get_page(start, limit, |account_id| {
+                    Some(TokenHolder {
+                        account_id: *account_id,
+                        balance: *edition_holders.get(account_id)?,
+                    })
+                }))
+            }
+            _ => Ok(Page {
+                items: GenericVec::new(),
+                next_cursor: None,
+            }),
+        }
+    }
+
+    pub fn get_holder_count(&self, token_id: TokenId) -> Result<u32, OperationError> {
+        if !self.minted_editions.contains_key(&token_id) {
+            return Err(OperationError::TokenNotFound);
+        }
+
+        match self.holders.get(&token_id) {
+            None => Ok(0),
+            Some(edition_holders) => Ok(edition_holders.len()),
+        }
+    }
+
+    pub fn get_account_balance_at(
+        &self,
+        account_id: AccountId,
+        token_id: TokenId,
+        block: BlockNumber,
+    ) -> Result<CarbonUnit, OperationError> {
+        if !self.minted_editions.contains_key(&token_id) {
+            return Err(OperationError::TokenNotFound);
+        }
+
+        Ok(self.history.get_balance_at(account_id, token_id, block))
+    }
+
+    pub fn get_supply_at(
+        &self,
+        token_id: TokenId,
+        block: BlockNumber,
+    ) -> Result<CarbonUnit, OperationError> {
+        if !self.minted_editions.contains_key(&token_id) {
+            return Err(OperationError::TokenNotFound);
+        }
+
+        Ok(self.history.get_supply_at(token_id, block))
+    }
+
+    pub fn create_snapshot(&mut self, name: String) -> SnapshotId {
+        self.history.create_snapshot(name)
+    }
+
+    pub fn get_snapshot_by_id(&self, snapshot_id: SnapshotId) -> Result<Snapshot, OperationError> {
+        self.history.get_snapshot_by_id(snapshot_id)
+    }
+
     pub fn transfer_token_all(
         &mut self,
         account_id: AccountId,
@@ -320,35 +1189,26 @@ impl Tracker {
         }
 
         let mut transfer_details = GenericVec::new();
+        let context_account_balances = self.balances.get(&account_id).unwrap();
 
-        if !self.balances.contains_key(&target_account_id) {
-            self.balances
-                .insert(target_account_id, StorageBox::new(StorageHashMap::new()));
-        }
-
-        if !self.balances.contains_key(&account_id) {
-            return Err(OperationError::InsufficientCarbonUnit);
-        }
-
-        let context_account_balances = self.balances.get_mut(&account_id).unwrap().as_mut();
+        for (token_id, token_amount) in context_account_balances.iter() {
+            if !self.is_active(*token_id) {
+                continue;
+            }
 
-        for (token_id, token_amount) in context_account_balances.into_iter() {
             transfer_details.push(TokenEdition {
                 id: *token_id,
                 amount: *token_amount,
             });
         }
 
-        *context_account_balances = StorageHashMap::new();
-        let target_account_balances = self.balances.get_mut(&target_account_id).unwrap().as_mut();
+        if transfer_details.is_empty() {
+            return Err(OperationError::TokenNotActive);
+        }
 
         for token_edition in &transfer_details {
-            match target_account_balances.get_mut(&token_edition.id) {
-                None => {
-                    target_account_balances.insert(token_edition.id, token_edition.amount);
-                }
-                Some(target_account_balance) => *target_account_balance += token_edition.amount,
-            }
+            let serials = self.debit_balance(account_id, token_edition.id, token_edition.amount)?;
+            self.credit_balance(target_account_id, token_edition.id, &serials);
         }
 
         Ok(transfer_details)
@@ -365,44 +1225,13 @@ impl Tracker {
             return Err(OperationError::CannotTransferZeroCarbonUnit);
         }
 
-        if !self.balances.contains_key(&target_account_id) {
-            self.balances
-                .insert(target_account_id, StorageBox::new(StorageHashMap::new()));
-        }
-
-        if !self.balances.contains_key(&account_id) {
-            return Err(OperationError::InsufficientCarbonUnit);
-        }
-
         if self.get_account_balance_by_id(account_id, token_id)? < token_amount {
             return Err(OperationError::InsufficientCarbonUnit);
         }
 
-        let context_account_balances = self.balances.get_mut(&account_id).unwrap().as_mut();
-
-        if !context_account_balances.contains_key(&token_id) {
-            return Err(OperationError::InsufficientCarbonUnit);
-        }
-
-        let context_account_balance = context_account_balances.get_mut(&token_id).unwrap();
-
-        if *context_account_balance < token_amount {
-            return Err(OperationError::InsufficientCarbonUnit);
-        }
-
-        *context_account_balance -= token_amount;
-
-        if *context_account_balance == 0 {
-            context_account_balances.take(&token_id);
-        }
-
-        let target_account_balances = self.balances.get_mut(&target_account_id).unwrap().as_mut();
-
-        if let Some(target_account_balance) = target_account_balances.get_mut(&token_id) {
-            *target_account_balance += token_amount;
-        } else {
-            target_account_balances.insert(token_id, token_amount);
-        }
+        self.check_is_active(token_id)?;
+        let serials = self.debit_balance(account_id, token_id, token_amount)?;
+        self.credit_balance(target_account_id, token_id, &serials);
 
         Ok(TokenEdition {
             id: token_id,
@@ -410,28 +1239,17 @@ impl Tracker {
         })
     }
 
-    pub fn transfer_token_by_year(
-        &mut self,
+    pub fn plan_allocation_by_year(
+        &self,
         account_id: AccountId,
-        target_account_id: AccountId,
         token_year: Year,
         token_amount: CarbonUnit,
+        strategy: &AllocationStrategy,
     ) -> Result<TokenEditions, OperationError> {
         if token_amount == 0 {
             return Err(OperationError::CannotTransferZeroCarbonUnit);
         }
 
-        let mut transfer_details = GenericVec::new();
-
-        if !self.balances.contains_key(&target_account_id) {
-            self.balances
-                .insert(target_account_id, StorageBox::new(StorageHashMap::new()));
-        }
-
-        if !self.balances.contains_key(&account_id) {
-            return Err(OperationError::InsufficientCarbonUnit);
-        }
-
         if !self.year_mapping.contains_key(&token_year) {
             return Err(OperationError::TokenNotFound);
         }
@@ -441,52 +1259,96 @@ impl Tracker {
         }
 
         let year_tokens = self.year_mapping.get(&token_year).unwrap().into_iter();
-        let context_account_balances = self.balances.get_mut(&account_id).unwrap().as_mut();
-        let mut pending_removal = GenericVec::new();
-        let mut remaining_amount_to_transfer = token_amount;
+        let context_account_balances = self.balances.get(&account_id).unwrap();
+        let mut candidates = GenericVec::new();
 
         for token_id in year_tokens {
-            if remaining_amount_to_transfer == 0 {
-                break;
+            if !self.is_active(*token_id) {
+                continue;
             }
 
-            if let Some(context_account_year_balance) = context_account_balances.get_mut(token_id) {
-                let transferred_balance_by_id;
+            if let Some(token_balance) = context_account_balances.get(token_id) {
+                candidates.push(TokenBalanceDetail {
+                    balance: *token_balance,
+                    detail: self.minted_editions.get(token_id).unwrap().clone(),
+                });
+            }
+        }
 
-                if *context_account_year_balance < remaining_amount_to_transfer {
-                    transferred_balance_by_id = *context_account_year_balance;
-                    *context_account_year_balance = 0;
-                    remaining_amount_to_transfer -= *context_account_year_balance;
-                } else {
-                    transferred_balance_by_id = remaining_amount_to_transfer;
-                    *context_account_year_balance -= remaining_amount_to_transfer;
-                    remaining_amount_to_transfer = 0;
-                }
+        let spendable_balance: CarbonUnit =
+            candidates.iter().map(|candidate| candidate.balance).sum();
 
-                if *context_account_year_balance == 0 {
-                    pending_removal.push(*token_id);
-                }
+        if spendable_balance < token_amount {
+            return Err(OperationError::InsufficientCarbonUnit);
+        }
 
-                transfer_details.push(TokenEdition {
-                    id: *token_id,
-                    amount: transferred_balance_by_id,
-                });
+        match strategy {
+            AllocationStrategy::OldestMintFirst => candidates.sort_by_key(|candidate| {
+                (candidate.detail.minted_block_number, candidate.detail.id)
+            }),
+            AllocationStrategy::NewestMintFirst => candidates.sort_by(|a, b| {
+                (b.detail.minted_block_number, b.detail.id)
+                    .cmp(&(a.detail.minted_block_number, a.detail.id))
+            }),
+            AllocationStrategy::LargestBalanceFirst => candidates.sort_by(|a, b| {
+                b.balance
+                    .cmp(&a.balance)
+                    .then(a.detail.id.cmp(&b.detail.id))
+            }),
+            AllocationStrategy::SmallestBalanceFirst => {
+                candidates.sort_by_key(|candidate| (candidate.balance, candidate.detail.id))
             }
+            AllocationStrategy::PreferredMinters(minters) => candidates.sort_by_key(|candidate| {
+                let preference = minters
+                    .iter()
+                    .position(|minter| *minter == candidate.detail.minter)
+                    .unwrap_or(minters.len());
+
+                (
+                    preference,
+                    candidate.detail.block_number,
+                    candidate.detail.id,
+                )
+            }),
         }
 
-        for token_id in pending_removal.into_iter() {
-            context_account_balances.take(&token_id);
+        let mut allocation = GenericVec::new();
+        let mut remaining_amount_to_allocate = token_amount;
+
+        for candidate in candidates {
+            if remaining_amount_to_allocate == 0 {
+                break;
+            }
+
+            let allocated_amount = candidate.balance.min(remaining_amount_to_allocate);
+            remaining_amount_to_allocate -= allocated_amount;
+            allocation.push(TokenEdition {
+                id: candidate.detail.id,
+                amount: allocated_amount,
+            });
         }
 
-        let target_account_balances = self.balances.get_mut(&target_account_id).unwrap().as_mut();
+        Ok(allocation)
+    }
+
+    pub fn transfer_token_by_year(
+        &mut self,
+        account_id: AccountId,
+        target_account_id: AccountId,
+        token_year: Year,
+        token_amount: CarbonUnit,
+        strategy: &AllocationStrategy,
+    ) -> Result<TokenEditions, OperationError> {
+        let transfer_details =
+            self.plan_allocation_by_year(account_id, token_year, token_amount, strategy)?;
 
         for token_edition in &transfer_details {
-            if let Some(target_account_balance) = target_account_balances.get_mut(&token_edition.id)
-            {
-                *target_account_balance += token_edition.amount;
-            } else {
-                target_account_balances.insert(token_edition.id, token_edition.amount);
-            }
+            self.transfer_token_by_id(
+                account_id,
+                target_account_id,
+                token_edition.id,
+                token_edition.amount,
+            )?;
         }
 
         Ok(transfer_details)
@@ -513,6 +1375,8 @@ impl Tracker {
                 return Err(OperationError::CannotTransferZeroCarbonUnit);
             }
 
+            self.check_is_active(token_edition.id)?;
+
             if !context_account_balances.contains_key(&token_edition.id) {
                 return Err(OperationError::InsufficientCarbonUnit);
             }
@@ -534,18 +1398,70 @@ impl Tracker {
         Ok(())
     }
 
+    pub fn transfer_token_batch(
+        &mut self,
+        account_id: AccountId,
+        params: &TokenTransferBatch,
+    ) -> Result<(), OperationError> {
+        let mut required_balances: BTreeMap<TokenId, CarbonUnit> = BTreeMap::new();
+
+        for (index, (_, token_editions)) in params.iter().enumerate() {
+            let leg_error = OperationError::BatchTransferFailed(index as u32);
+
+            if token_editions.is_empty() {
+                return Err(leg_error);
+            }
+
+            for token_edition in token_editions {
+                if token_edition.amount == 0 || !self.is_active(token_edition.id) {
+                    return Err(leg_error);
+                }
+
+                let required_balance = required_balances.entry(token_edition.id).or_insert(0);
+                *required_balance += token_edition.amount;
+
+                match self.get_account_balance_by_id(account_id, token_edition.id) {
+                    Err(_) => return Err(leg_error),
+                    Ok(token_balance) if token_balance < *required_balance => {
+                        return Err(leg_error)
+                    }
+                    Ok(_) => {}
+                }
+            }
+        }
+
+        for (target_account_id, token_editions) in params {
+            for token_edition in token_editions {
+                self.transfer_token_by_id(
+                    account_id,
+                    *target_account_id,
+                    token_edition.id,
+                    token_edition.amount,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn retire_token_id(
         &mut self,
         account_id: AccountId,
         token_id: TokenId,
         retirement_amount: CarbonUnit,
-    ) -> Result<(), OperationError> {
-        self.transfer_token_by_id(
-            account_id,
-            get_blackhole_address(),
-            token_id,
-            retirement_amount,
-        )?;
+    ) -> Result<GenericVec<SerialRange>, OperationError> {
+        if retirement_amount == 0 {
+            return Err(OperationError::CannotTransferZeroCarbonUnit);
+        }
+
+        if self.get_account_balance_by_id(account_id, token_id)? < retirement_amount {
+            return Err(OperationError::InsufficientCarbonUnit);
+        }
+
+        self.check_is_active(token_id)?;
+
+        let retired_serials = self.debit_balance(account_id, token_id, retirement_amount)?;
+        self.credit_balance(get_blackhole_address(), token_id, &retired_serials);
         let edition_detail = self.minted_editions.get_mut(&token_id).unwrap();
 
         if edition_detail.supply < retirement_amount {
@@ -554,7 +1470,205 @@ impl Tracker {
 
         edition_detail.supply -= retirement_amount;
         edition_detail.retired += retirement_amount;
+        let edition_supply = edition_detail.supply;
+        self.history.record_supply(token_id, edition_supply);
+
+        Ok(retired_serials)
+    }
+
+    pub fn cancel_buffer_credit(
+        &mut self,
+        token_id: TokenId,
+        amount: CarbonUnit,
+    ) -> Result<(), OperationError> {
+        if amount == 0 {
+            return Err(OperationError::CannotTransferZeroCarbonUnit);
+        }
+
+        if self.get_account_balance_by_id(get_buffer_address(), token_id)? < amount {
+            return Err(OperationError::InsufficientCarbonUnit);
+        }
+
+        self.debit_balance(get_buffer_address(), token_id, amount)?;
+        let edition_detail = self.minted_editions.get_mut(&token_id).unwrap();
+
+        if edition_detail.supply < amount {
+            return Err(OperationError::BlockchainCorrupted);
+        }
+
+        edition_detail.supply -= amount;
+        let edition_supply = edition_detail.supply;
+        self.history.record_supply(token_id, edition_supply);
 
         Ok(())
     }
+
+    pub fn get_account_serials_by_id(
+        &self,
+        account_id: AccountId,
+        token_id: TokenId,
+    ) -> Result<GenericVec<SerialRange>, OperationError> {
+        if !self.minted_editions.contains_key(&token_id) {
+            return Err(OperationError::TokenNotFound);
+        }
+
+        match self.serials.get(&(account_id, token_id)) {
+            None => Ok(GenericVec::new()),
+            Some(account_serials) => Ok(account_serials.clone()),
+        }
+    }
+
+    fn credit_issuance(
+        &mut self,
+        token_id: TokenId,
+        beneficiary: MintBeneficiaryAccount,
+        serials: SerialRange,
+        buffer_amount: CarbonUnit,
+    ) {
+        let mut issued_serials = GenericVec::new();
+        issued_serials.push(serials);
+        let beneficiary_serials =
+            serial::take_lowest(&mut issued_serials, serials.len() - buffer_amount);
+        self.credit_balance(beneficiary, token_id, &beneficiary_serials);
+
+        if buffer_amount > 0 {
+            self.credit_balance(get_buffer_address(), token_id, &issued_serials);
+        }
+    }
+
+    fn record_issuance(&mut self, issuance: Issuance) {
+        if !self.issuances.contains_key(&issuance.token_id) {
+            self.issuances
+                .insert(issuance.token_id, StorageBox::new(StorageVec::new()));
+        }
+
+        self.issuances
+            .get_mut(&issuance.token_id)
+            .unwrap()
+            .push(issuance);
+    }
+
+    fn credit_balance(
+        &mut self,
+        account_id: AccountId,
+        token_id: TokenId,
+        serials: &[SerialRange],
+    ) {
+        let amount = serial::count(serials);
+
+        match self.serials.get_mut(&(account_id, token_id)) {
+            None => {
+                self.serials
+                    .insert((account_id, token_id), serials.to_vec());
+            }
+            Some(account_serials) => serial::merge(account_serials, serials),
+        }
+
+        if !self.balances.contains_key(&account_id) {
+            self.balances
+                .insert(account_id, StorageBox::new(StorageHashMap::new()));
+        }
+
+        let account_balances = self.balances.get_mut(&account_id).unwrap();
+        let account_balance = match account_balances.get_mut(&token_id) {
+            None => {
+                account_balances.insert(token_id, amount);
+                amount
+            }
+            Some(account_balance) => {
+                *account_balance += amount;
+                *account_balance
+            }
+        };
+
+        if !self.balance_ids.contains_key(&account_id) {
+            self.balance_ids
+                .insert(account_id, StorageBox::new(KeyIndex::default()));
+        }
+
+        self.balance_ids
+            .get_mut(&account_id)
+            .unwrap()
+            .insert(token_id);
+        self.record_balance_change(account_id, token_id, account_balance);
+    }
+
+    fn debit_balance(
+        &mut self,
+        account_id: AccountId,
+        token_id: TokenId,
+        amount: CarbonUnit,
+    ) -> Result<GenericVec<SerialRange>, OperationError> {
+        let account_balances = match self.balances.get_mut(&account_id) {
+            None => return Err(OperationError::InsufficientCarbonUnit),
+            Some(account_balances) => account_balances,
+        };
+        let account_balance = match account_balances.get_mut(&token_id) {
+            None => return Err(OperationError::InsufficientCarbonUnit),
+            Some(account_balance) => account_balance,
+        };
+
+        if *account_balance < amount {
+            return Err(OperationError::InsufficientCarbonUnit);
+        }
+
+        *account_balance -= amount;
+        let remaining_balance = *account_balance;
+
+        if remaining_balance == 0 {
+            account_balances.take(&token_id);
+
+            if let Some(account_token_ids) = self.balance_ids.get_mut(&account_id) {
+                account_token_ids.remove(&token_id);
+            }
+        }
+
+        let account_serials = match self.serials.get_mut(&(account_id, token_id)) {
+            None => return Err(OperationError::BlockchainCorrupted),
+            Some(account_serials) => account_serials,
+        };
+        let debited_serials = serial::take_lowest(account_serials, amount);
+
+        if account_serials.is_empty() {
+            self.serials.take(&(account_id, token_id));
+        }
+
+        self.record_balance_change(account_id, token_id, remaining_balance);
+
+        Ok(debited_serials)
+    }
+
+    fn record_balance_change(
+        &mut self,
+        account_id: AccountId,
+        token_id: TokenId,
+        balance: CarbonUnit,
+    ) {
+        if account_id == get_blackhole_address() {
+            return;
+        }
+
+        self.history.record_balance(account_id, token_id, balance);
+
+        if !self.holders.contains_key(&token_id) {
+            self.holders
+                .insert(token_id, StorageBox::new(StorageHashMap::new()));
+        }
+
+        if !self.holder_ids.contains_key(&token_id) {
+            self.holder_ids
+                .insert(token_id, StorageBox::new(KeyIndex::default()));
+        }
+
+        let edition_holders = self.holders.get_mut(&token_id).unwrap();
+        let edition_holder_ids = self.holder_ids.get_mut(&token_id).unwrap();
+
+        if balance == 0 {
+            edition_holders.take(&account_id);
+            edition_holder_ids.remove(&account_id);
+        } else {
+            edition_holders.insert(account_id, balance);
+            edition_holder_ids.insert(account_id);
+        }
+    }
 }
@@ -1,5 +1,44 @@
-use crate::environment::AccountId;
+use crate::environment::{AccountId, Hash};
+use crate::{PoolId, RegistryId};
+use ink_env::hash::{Blake2x256, CryptoHash, HashOutput};
 
 pub fn get_blackhole_address() -> AccountId {
     AccountId::from([0x00; 32])
 }
+
+pub fn get_buffer_address() -> AccountId {
+    AccountId::from([0xee; 32])
+}
+
+pub fn get_pool_address(pool_id: PoolId) -> AccountId {
+    let mut address = [0xff; 32];
+    address[28..].copy_from_slice(&pool_id.to_be_bytes());
+
+    AccountId::from(address)
+}
+
+pub fn get_pool_id(account_id: &AccountId) -> Option<PoolId> {
+    let address: &[u8; 32] = account_id.as_ref();
+
+    if !address[..28].iter().all(|byte| *byte == 0xff) {
+        return None;
+    }
+
+    let mut pool_id = [0; 4];
+    pool_id.copy_from_slice(&address[28..]);
+
+    Some(PoolId::from_be_bytes(pool_id))
+}
+
+pub fn is_synthetic_address(account_id: &AccountId) -> bool {
+    *account_id == get_blackhole_address()
+        || *account_id == get_buffer_address()
+        || get_pool_id(account_id).is_some()
+}
+
+pub fn hash_registry_id(registry_id: &RegistryId) -> Hash {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    Blake2x256::hash(registry_id.as_bytes(), &mut output);
+
+    Hash::from(output)
+}
@@ -0,0 +1,188 @@
+use crate::environment::{AccountId, BlockNumber, DefaultEnvironment, Hash, Timestamp};
+use crate::pagination::{KeyIndex, Page};
+use crate::{
+    Decode, DocumentVersion, Encode, GenericVec, OperationError, RegistryId, SpreadLayout,
+    StorageHashMap, String,
+};
+use ink_env::{block_number, block_timestamp};
+use ink_storage::traits::PackedLayout;
+
+#[derive(Clone, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AddParams {
+    pub id: AccountId,
+    pub alias: String,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Detail {
+    pub id: AccountId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub alias: String,
+}
+
+#[derive(Clone, Debug, SpreadLayout, PackedLayout, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Attestation {
+    pub verifier: AccountId,
+    pub block_number: BlockNumber,
+    pub timestamp: Timestamp,
+    pub report_hash: Option<Hash>,
+    pub document_version: DocumentVersion,
+}
+
+#[derive(Debug, Default, SpreadLayout)]
+pub struct Panel {
+    verifiers: StorageHashMap<AccountId, Detail>,
+    verifier_ids: KeyIndex<AccountId>,
+    attestations: StorageHashMap<RegistryId, GenericVec<Attestation>>,
+    threshold: u32,
+}
+
+impl From<AddParams> for Detail {
+    fn from(params: AddParams) -> Self {
+        Detail {
+            id: params.id,
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            alias: params.alias,
+        }
+    }
+}
+
+impl Panel {
+    pub fn contains(&self, id: AccountId) -> bool {
+        self.verifiers.contains_key(&id)
+    }
+
+    pub fn get_by_id(&self, id: AccountId) -> Result<Detail, OperationError> {
+        match self.verifiers.get(&id) {
+            None => Err(OperationError::VerifierNotFound),
+            Some(detail) => Ok(detail.clone()),
+        }
+    }
+
+    pub fn add(&mut self, params: AddParams) -> Result<Detail, OperationError> {
+        if self.verifiers.contains_key(&params.id) {
+            return Err(OperationError::VerifierAlreadyRegistered);
+        }
+
+        let detail: Detail = params.into();
+        self.verifiers.insert(detail.id, detail.clone());
+        self.verifier_ids.insert(detail.id);
+
+        Ok(detail)
+    }
+
+    pub fn remove(&mut self, id: AccountId) -> Result<Detail, OperationError> {
+        match self.verifiers.take(&id) {
+            None => Err(OperationError::VerifierNotFound),
+            Some(detail) => {
+                self.verifier_ids.remove(&id);
+
+                Ok(detail)
+            }
+        }
+    }
+
+    pub fn get_threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    pub fn set_threshold(&mut self, threshold: u32) {
+        self.threshold = threshold;
+    }
+
+    pub fn attest(
+        &mut self,
+        verifier: AccountId,
+        minter: AccountId,
+        registry_id: &RegistryId,
+        report_hash: Option<Hash>,
+        document_version: DocumentVersion,
+    ) -> Result<Attestation, OperationError> {
+        if !self.verifiers.contains_key(&verifier) {
+            return Err(OperationError::Unauthorized);
+        }
+
+        if verifier == minter {
+            return Err(OperationError::VerifierNotIndependent);
+        }
+
+        let attestation = Attestation {
+            verifier,
+            block_number: block_number::<DefaultEnvironment>(),
+            timestamp: block_timestamp::<DefaultEnvironment>(),
+            report_hash,
+            document_version,
+        };
+
+        match self.attestations.get_mut(registry_id) {
+            None => {
+                let mut attestations = GenericVec::new();
+                attestations.push(attestation.clone());
+                self.attestations.insert(registry_id.clone(), attestations);
+            }
+            Some(attestations) => {
+                if attestations.iter().any(|attested| {
+                    attested.verifier == verifier && attested.document_version == document_version
+                }) {
+                    return Err(OperationError::VerifierAlreadyAttested);
+                }
+
+                attestations.retain(|attested| attested.verifier != verifier);
+
+                attestations.push(attestation.clone());
+            }
+        }
+
+        Ok(attestation)
+    }
+
+    pub fn get_attestations(&self, registry_id: &RegistryId) -> GenericVec<Attestation> {
+        match self.attestations.get(registry_id) {
+            None => GenericVec::new(),
+            Some(attestations) => attestations.clone(),
+        }
+    }
+
+    pub fn get_attestation_count(
+        &self,
+        registry_id: &RegistryId,
+        document_version: DocumentVersion,
+    ) -> u32 {
+        match self.attestations.get(registry_id) {
+            None => 0,
+            Some(attestations) => attestations
+                .iter()
+                .filter(|attestation| {
+                    attestation.document_version == document_version
+                        && self.verifiers.contains_key(&attestation.verifier)
+                })
+                .count() as u32,
+        }
+    }
+
+    pub fn check_threshold(
+        &self,
+        registry_id: &RegistryId,
+        document_version: DocumentVersion,
+    ) -> Result<(), OperationError> {
+        if self.get_attestation_count(registry_id, document_version) < self.threshold {
+            return Err(OperationError::VerifierAttestationsInsufficient);
+        }
+
+        Ok(())
+    }
+
+    pub fn clear_attestations(&mut self, registry_id: &RegistryId) {
+        self.attestations.take(registry_id);
+    }
+
+    pub fn list_page(&self, start: Option<u32>, limit: u32) -> Page<u32, Detail> {
+        self.verifier_ids
+            .get_page(start, limit, |id| self.verifiers.get(id).cloned())
+    }
+}